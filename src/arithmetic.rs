@@ -1,5 +1,6 @@
 
 #[doc(hidden)]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) trait AddSubArithmetic<T> {
 	type Output;
 	fn add_other(self, rhs: T) -> Self::Output;
@@ -9,6 +10,7 @@ pub(crate) trait AddSubArithmetic<T> {
 }
 
 #[doc(hidden)]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) trait MulDivScalar {
 	type Output;
 	fn multiply_scalar(self, rhs: f32) -> Self::Output;
@@ -19,14 +21,17 @@ pub(crate) trait MulDivScalar {
 }
 
 #[doc(hidden)]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 macro_rules! use_impl_ops {
 	() => {
 		use core::ops::{Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign};
 	};
 }
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) use use_impl_ops;
 
 #[doc(hidden)]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 macro_rules! impl_add {
 	($($t:ty)*) => {
 		$(
@@ -60,9 +65,11 @@ macro_rules! impl_add {
 		)*
 	};
 }
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) use impl_add;
 
 #[doc(hidden)]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 macro_rules! impl_sub {
 	($($t:ty)*) => {
 		$(
@@ -96,6 +103,7 @@ macro_rules! impl_sub {
 		)*
 	};
 }
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) use impl_sub;
 
 #[doc(hidden)]
@@ -152,6 +160,7 @@ macro_rules! impl_mul {
 pub(crate) use impl_mul;
 
 #[doc(hidden)]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 macro_rules! impl_div {
 	($($v1:ty, $v2:ty => $out:ty: $fn:ident)*) => {
 		$(
@@ -202,4 +211,5 @@ macro_rules! impl_div {
 		)*
 	};
 }
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
 pub(crate) use impl_div;
@@ -0,0 +1,26 @@
+
+use crate::Math;
+
+// Private Functions
+impl Math {
+	/// Compares each named component with it's expected value, used to build descriptive approximate assertions
+	/// - **components**: The name, expected value, and actual value of each component
+	/// - **epsilon**: The epsilon (smallest possible difference between numbers) to check with
+	/// 
+	/// **Returns**: Returns an error naming the first component that diverges and by how much
+	pub(crate) fn approx_components(components: &[(&str, f32, f32)], epsilon: f32) -> Result<(), String> {
+		for (name, expected, actual) in components {
+			if !Math::approx_epsilon(*expected, *actual, epsilon) {
+				return Err(format!(
+					"{} differs by {} (expected {}, actual {})",
+					name,
+					Math::abs(actual - expected),
+					expected,
+					actual
+				));
+			}
+		}
+		
+		Ok(())
+	}
+}
@@ -0,0 +1,427 @@
+
+use crate::{Vector3, Math};
+#[cfg(not(feature = "no_rays"))]
+use crate::{Ray3, interfaces::IRaycast, collision::{RaycastInfo, RaycastInfoBuilder}};
+
+/// An axis-aligned bounding box that holds a center and the extents (half the size) of the box
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Bounds3 {
+	/// The center of the box
+	center: Vector3,
+	/// The extents of the box, which is half of the size of the box
+	extents: Vector3,
+}
+
+/// Constructors
+impl Bounds3 {
+	/// Creates a new axis-aligned bounding box
+	/// - **center**: The center of the box
+	/// - **extents**: The extents of the box, which is half of the size of the box
+	/// 
+	/// **Returns**: Returns a new axis-aligned bounding box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(Vector3::one(), bounds.center());
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), bounds.extents());
+	/// ```
+	pub fn new(center: Vector3, extents: Vector3) -> Self { Bounds3 { center, extents: extents.abs() } }
+	
+	/// Creates a new axis-aligned bounding box from it's minimum and maximum corners
+	/// - **min**: The corner of the box with the smallest components
+	/// - **max**: The corner of the box with the largest components
+	/// 
+	/// **Returns**: Returns a new axis-aligned bounding box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::from_min_max(Vector3::new(-1.0, 0.0, 2.0), Vector3::new(3.0, 2.0, 4.0));
+	/// assert_eq!(Vector3::new(1.0, 1.0, 3.0), bounds.center());
+	/// assert_eq!(Vector3::new(2.0, 1.0, 1.0), bounds.extents());
+	/// ```
+	pub fn from_min_max(min: Vector3, max: Vector3) -> Self {
+		Bounds3::new(0.5 * (min + max), 0.5 * (max - min))
+	}
+}
+
+/// Properties
+impl Bounds3 {
+	/// Gets the center of the box
+	/// 
+	/// **Returns**: Returns the center of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::one());
+	/// assert_eq!(Vector3::one(), bounds.center());
+	/// ```
+	pub fn center(&self) -> Vector3 { self.center }
+	
+	/// Sets the center of the box
+	/// - **value**: The value to set the center to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let mut bounds = Bounds3::new(Vector3::one(), Vector3::one());
+	/// bounds.set_center(Vector3::zero());
+	/// assert_eq!(Vector3::zero(), bounds.center());
+	/// ```
+	pub fn set_center(&mut self, value: Vector3) { self.center = value; }
+	
+	/// Gets the extents of the box, which is half of the size of the box
+	/// 
+	/// **Returns**: Returns the extents of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::zero(), Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), bounds.extents());
+	/// ```
+	pub fn extents(&self) -> Vector3 { self.extents }
+	
+	/// Sets the extents of the box, which is half of the size of the box
+	/// - **value**: The value to set the extents to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let mut bounds = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// bounds.set_extents(Vector3::new(-2.0, 2.0, 2.0));
+	/// assert_eq!(Vector3::new(2.0, 2.0, 2.0), bounds.extents());
+	/// ```
+	pub fn set_extents(&mut self, value: Vector3) { self.extents = value.abs(); }
+	
+	/// Gets the corner of the box with the largest components
+	/// 
+	/// **Returns**: Returns the maximum corner of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(Vector3::new(2.0, 3.0, 4.0), bounds.max());
+	/// ```
+	pub fn max(&self) -> Vector3 { self.center + self.extents }
+	
+	/// Gets the corner of the box with the smallest components
+	/// 
+	/// **Returns**: Returns the minimum corner of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(Vector3::new(0.0, -1.0, -2.0), bounds.min());
+	/// ```
+	pub fn min(&self) -> Vector3 { self.center - self.extents }
+	
+	/// Gets the size of the box, which is double the extents
+	/// 
+	/// **Returns**: Returns the size of the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::new(1.0, 2.0, 3.0));
+	/// assert_eq!(Vector3::new(2.0, 4.0, 6.0), bounds.size());
+	/// ```
+	pub fn size(&self) -> Vector3 { 2.0 * self.extents }
+}
+
+/// Public Methods
+impl Bounds3 {
+	/// Gets the point within the box that is closest to the given point
+	/// - **point**: The point to get the closest point from
+	/// 
+	/// **Returns**: Returns the closest point within the box, which is the point itself if it's inside the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// assert_eq!(Vector3::new(1.0, 0.5, -1.0), bounds.closest_point(Vector3::new(3.0, 0.5, -2.0)));
+	/// assert_eq!(Vector3::new(0.5, 0.5, 0.5), bounds.closest_point(Vector3::new(0.5, 0.5, 0.5)));
+	/// ```
+	pub fn closest_point(&self, point: Vector3) -> Vector3 { point.clamp(self.min(), self.max()) }
+	
+	/// Finds if the point is inside the box, including the surface of the box
+	/// - **point**: The point to check with
+	/// 
+	/// **Returns**: Returns true if the point is inside the box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// assert!(bounds.contains(Vector3::new(0.5, -0.5, 0.0)));
+	/// assert!(bounds.contains(Vector3::new(1.0, 1.0, 1.0)));
+	/// assert!(!bounds.contains(Vector3::new(1.5, 0.0, 0.0)));
+	/// ```
+	pub fn contains(&self, point: Vector3) -> bool {
+		let min = self.min();
+		let max = self.max();
+		
+		point.x() >= min.x() && point.x() <= max.x()
+		&& point.y() >= min.y() && point.y() <= max.y()
+		&& point.z() >= min.z() && point.z() <= max.z()
+	}
+	
+	/// Grows the box just enough to include the given point
+	/// - **point**: The point the box should include
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let mut bounds = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// bounds.encapsulate(Vector3::new(3.0, 0.5, -2.0));
+	/// assert_eq!(Vector3::new(-1.0, -1.0, -2.0), bounds.min());
+	/// assert_eq!(Vector3::new(3.0, 1.0, 1.0), bounds.max());
+	/// assert!(bounds.contains(Vector3::new(3.0, 0.5, -2.0)));
+	/// bounds.encapsulate(Vector3::zero());
+	/// assert_eq!(Vector3::new(3.0, 1.0, 1.0), bounds.max());
+	/// ```
+	pub fn encapsulate(&mut self, point: Vector3) {
+		*self = Bounds3::from_min_max(self.min().min(point), self.max().max(point));
+	}
+	
+	/// Expands the box by moving every side outwards by the given amount
+	/// - **amount**: The amount to move each side by, where a negative amount shrinks the box
+	/// 
+	/// **Returns**: Returns the expanded box, where shrinking stops once the box has no size left
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::one()).expand(0.5);
+	/// assert_eq!(Vector3::one(), bounds.center());
+	/// assert_eq!(Vector3::new(1.5, 1.5, 1.5), bounds.extents());
+	/// let bounds = Bounds3::new(Vector3::one(), Vector3::new(1.0, 2.0, 3.0)).expand(-1.5);
+	/// assert_eq!(Vector3::new(0.0, 0.5, 1.5), bounds.extents());
+	/// ```
+	pub fn expand(self, amount: f32) -> Self {
+		Bounds3::new(self.center, (self.extents + Vector3::new(amount, amount, amount)).max(Vector3::zero()))
+	}
+	
+	/// Finds if the two boxes overlap, including when they only touch
+	/// - **other**: The other box to check with
+	/// 
+	/// **Returns**: Returns true if the two boxes overlap
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let a = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// let b = Bounds3::new(Vector3::new(1.5, 0.5, 0.0), Vector3::one());
+	/// let c = Bounds3::new(Vector3::new(0.0, 3.0, 0.0), Vector3::one());
+	/// assert!(a.intersects(b));
+	/// assert!(b.intersects(a));
+	/// assert!(!a.intersects(c));
+	/// assert!(!c.intersects(a));
+	/// ```
+	pub fn intersects(&self, other: Bounds3) -> bool {
+		let min = self.min();
+		let max = self.max();
+		let other_min = other.min();
+		let other_max = other.max();
+		
+		min.x() <= other_max.x() && max.x() >= other_min.x()
+		&& min.y() <= other_max.y() && max.y() >= other_min.y()
+		&& min.z() <= other_max.z() && max.z() >= other_min.z()
+	}
+	
+	/// Merges the two boxes into the smallest box that includes both of them
+	/// - **a**: The first box to merge
+	/// - **b**: The second box to merge
+	/// 
+	/// **Returns**: Returns the merged box
+	/// #### Remarks
+	/// This is the associated function form of [`Bounds3::union`], which is handy when the boxes are borrowed,
+	/// such as when folding over a slice of boxes
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let a = Bounds3::from_min_max(Vector3::zero(), Vector3::one());
+	/// let b = Bounds3::from_min_max(Vector3::new(3.0, -2.0, 0.0), Vector3::new(4.0, -1.0, 2.0));
+	/// let merged = Bounds3::merge(&a, &b);
+	/// assert_eq!(Vector3::new(0.0, -2.0, 0.0), merged.min());
+	/// assert_eq!(Vector3::new(4.0, 1.0, 2.0), merged.max());
+	/// assert_eq!(a.union(b), merged);
+	/// let boxes = [a, b, Bounds3::new(Vector3::new(0.0, 5.0, 0.0), Vector3::one())];
+	/// let all = boxes.iter().fold(boxes[0], |acc, bounds| Bounds3::merge(&acc, bounds));
+	/// assert_eq!(Vector3::new(-1.0, -2.0, -1.0), all.min());
+	/// assert_eq!(Vector3::new(4.0, 6.0, 2.0), all.max());
+	/// ```
+	pub fn merge(a: &Bounds3, b: &Bounds3) -> Self { a.union(*b) }
+	
+	/// Keeps a moving point inside of the box, clamping the position back into the box and reflecting the
+	/// velocity on each axis that the point left the box through
+	/// - **position**: The position of the point
+	/// - **velocity**: The velocity of the point
+	/// 
+	/// **Returns**: Returns the corrected position and velocity, which are unchanged if the position is inside the box
+	/// #### Remarks
+	/// Each reflected component is flipped to point back into the box, so a point that's outside while
+	/// already moving back towards the box keeps it's velocity on that axis
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// let (position, velocity) = bounds.reflect_inside(Vector3::new(1.25, 0.5, 0.0), Vector3::new(2.0, 1.0, -1.0));
+	/// assert_eq!(Vector3::new(1.0, 0.5, 0.0), position);
+	/// assert_eq!(Vector3::new(-2.0, 1.0, -1.0), velocity);
+	/// let (position, velocity) = bounds.reflect_inside(Vector3::new(-1.5, 2.0, 0.0), Vector3::new(-1.0, 3.0, 0.0));
+	/// assert_eq!(Vector3::new(-1.0, 1.0, 0.0), position);
+	/// assert_eq!(Vector3::new(1.0, -3.0, 0.0), velocity);
+	/// let (position, velocity) = bounds.reflect_inside(Vector3::new(0.5, -0.5, 0.25), Vector3::new(2.0, 1.0, -1.0));
+	/// assert_eq!(Vector3::new(0.5, -0.5, 0.25), position);
+	/// assert_eq!(Vector3::new(2.0, 1.0, -1.0), velocity);
+	/// ```
+	pub fn reflect_inside(&self, position: Vector3, velocity: Vector3) -> (Vector3, Vector3) {
+		let min = self.min();
+		let max = self.max();
+		let mut position = position;
+		let mut velocity = velocity;
+		
+		for axis in 0..3 {
+			if position[axis] < min[axis] {
+				position[axis] = min[axis];
+				velocity[axis] = Math::abs(velocity[axis]);
+			}
+			else if position[axis] > max[axis] {
+				position[axis] = max[axis];
+				velocity[axis] = -Math::abs(velocity[axis]);
+			}
+		}
+		
+		(position, velocity)
+	}
+	
+	/// Merges the two boxes into the smallest box that includes both of them
+	/// - **other**: The other box to merge with
+	/// 
+	/// **Returns**: Returns the merged box
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Bounds3;
+	/// let a = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// let b = Bounds3::new(Vector3::new(4.0, 0.0, 0.0), Vector3::new(1.0, 2.0, 0.5));
+	/// let merged = a.union(b);
+	/// assert_eq!(Vector3::new(-1.0, -2.0, -1.0), merged.min());
+	/// assert_eq!(Vector3::new(5.0, 2.0, 1.0), merged.max());
+	/// for corner in [a.min(), a.max(), b.min(), b.max()] {
+	///     assert!(merged.contains(corner));
+	/// }
+	/// ```
+	pub fn union(self, other: Bounds3) -> Self {
+		Bounds3::from_min_max(self.min().min(other.min()), self.max().max(other.max()))
+	}
+}
+
+#[cfg(not(feature = "no_rays"))]
+impl IRaycast for Bounds3 {
+	/// Raycasts with the given ray using the slab method
+	/// - **ray**: The ray to raycast with
+	/// 
+	/// **Returns**: Returns the information on the raycast, with the point and normal of the face the ray entered through
+	/// #### Remarks
+	/// A ray that starts inside of the box is considered a hit at a distance of `0.0`, where the point
+	/// is the origin of the ray and the normal is zero since no face was crossed
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Ray3, interfaces::IRaycast};
+	/// # use mathx::collision::Bounds3;
+	/// let bounds = Bounds3::new(Vector3::zero(), Vector3::one());
+	/// let info = bounds.raycast(Ray3::new(Vector3::new(0.5, 0.0, -5.0), Vector3::forward()));
+	/// assert!(info.is_hit());
+	/// assert_eq!(4.0, info.distance());
+	/// assert_eq!(Vector3::new(0.5, 0.0, -1.0), info.point());
+	/// assert_eq!(Vector3::back(), info.normal());
+	/// let info = bounds.raycast(Ray3::new(Vector3::new(0.5, 0.0, -5.0), Vector3::back()));
+	/// assert!(!info.is_hit());
+	/// let info = bounds.raycast(Ray3::new(Vector3::new(0.0, 3.0, -5.0), Vector3::forward()));
+	/// assert!(!info.is_hit());
+	/// let info = bounds.raycast(Ray3::new(Vector3::new(0.0, 0.5, 0.0), Vector3::up()));
+	/// assert!(info.is_hit());
+	/// assert_eq!(0.0, info.distance());
+	/// assert_eq!(Vector3::new(0.0, 0.5, 0.0), info.point());
+	/// assert_eq!(Vector3::zero(), info.normal());
+	/// ```
+	fn raycast(&self, ray: Ray3) -> RaycastInfo {
+		let origin = ray.origin();
+		let direction = ray.direction();
+		let min = self.min();
+		let max = self.max();
+		let mut enter = f32::NEG_INFINITY;
+		let mut exit = f32::INFINITY;
+		let mut normal = Vector3::zero();
+		
+		for axis in 0..3 {
+			if direction[axis] == 0.0 {
+				if origin[axis] < min[axis] || origin[axis] > max[axis] {
+					return RaycastInfo::empty();
+				}
+				continue;
+			}
+			
+			let inverse = 1.0 / direction[axis];
+			let mut near = (min[axis] - origin[axis]) * inverse;
+			let mut far = (max[axis] - origin[axis]) * inverse;
+			
+			if near > far { core::mem::swap(&mut near, &mut far); }
+			if near > enter {
+				enter = near;
+				normal = Vector3::zero();
+				normal[axis] = if direction[axis] > 0.0 { -1.0 } else { 1.0 };
+			}
+			if far < exit { exit = far; }
+			if enter > exit || exit < 0.0 {
+				return RaycastInfo::empty();
+			}
+		}
+		
+		if enter < 0.0 {
+			return RaycastInfoBuilder::new()
+				.set_hit(true)
+				.set_distance(0.0)
+				.set_normal(Vector3::zero())
+				.set_point(origin)
+				.build();
+		}
+		
+		RaycastInfoBuilder::new()
+			.set_hit(true)
+			.set_distance(enter)
+			.set_normal(normal)
+			.set_point(ray.get_point(enter))
+			.build()
+	}
+}
+
+unsafe impl Send for Bounds3 {}
+unsafe impl Sync for Bounds3 {}
+
+impl Eq for Bounds3 {}
+impl PartialEq for Bounds3 {
+	fn eq(&self, other: &Self) -> bool {
+		self.center == other.center
+		&& self.extents == other.extents
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Bounds3 {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&format!("{{ center: {}, extents: {} }}", self.center, self.extents))
+	}
+}
@@ -3,3 +3,13 @@
 mod raycast_info;
 #[cfg(not(feature = "no_vectors"))]
 pub use raycast_info::{RaycastInfo, RaycastInfoBuilder};
+
+#[cfg(not(feature = "no_vectors"))]
+mod bounds;
+#[cfg(not(feature = "no_vectors"))]
+pub use bounds::Bounds3;
+
+#[cfg(not(feature = "no_vectors"))]
+mod sphere;
+#[cfg(not(feature = "no_vectors"))]
+pub use sphere::Sphere;
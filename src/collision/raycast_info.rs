@@ -34,9 +34,9 @@ impl RaycastInfoBuilder {
 	pub fn build(self) -> RaycastInfo {
 		RaycastInfo {
 			is_hit: self.is_hit,
-			point: self.point.unwrap_or(Vector3::zero()),
-			normal: self.normal.unwrap_or(Vector3::zero()),
-			uv: self.uv.unwrap_or(Vector2::zero()),
+			point: self.point.unwrap_or_default(),
+			normal: self.normal.unwrap_or_default(),
+			uv: self.uv.unwrap_or_default(),
 			distance: self.distance.unwrap_or(0.0),
 		}
 	}
@@ -0,0 +1,297 @@
+
+use crate::{Vector3, Math};
+#[cfg(not(feature = "no_rays"))]
+use crate::{Ray3, interfaces::IRaycast, collision::{RaycastInfo, RaycastInfoBuilder}};
+
+/// A sphere that holds a center and a radius
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Sphere {
+	/// The center of the sphere
+	center: Vector3,
+	/// The radius of the sphere
+	radius: f32,
+}
+
+/// Constructors
+impl Sphere {
+	/// Creates a new sphere
+	/// - **center**: The center of the sphere
+	/// - **radius**: The radius of the sphere
+	/// 
+	/// **Returns**: Returns a new sphere
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let sphere = Sphere::new(Vector3::one(), 2.0);
+	/// assert_eq!(Vector3::one(), sphere.center());
+	/// assert_eq!(2.0, sphere.radius());
+	/// let sphere = Sphere::new(Vector3::one(), -2.0);
+	/// assert_eq!(2.0, sphere.radius());
+	/// ```
+	pub fn new(center: Vector3, radius: f32) -> Self { Sphere { center, radius: Math::abs(radius) } }
+	
+	/// Creates a sphere that encloses all of the points, using Ritter's bounding sphere algorithm
+	/// - **points**: The points that the sphere should enclose
+	/// 
+	/// **Returns**: Returns the sphere enclosing the points, returns `None` if there are no points
+	/// #### Remarks
+	/// The sphere is a quick approximation that's usually a little larger than the smallest possible sphere
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let points = [
+	///     Vector3::new(1.0, 2.0, 0.5),
+	///     Vector3::new(-3.0, 0.0, 1.0),
+	///     Vector3::new(2.0, -1.0, -2.0),
+	///     Vector3::new(0.0, 4.0, 3.0),
+	///     Vector3::new(-1.0, -2.0, 2.5),
+	/// ];
+	/// let sphere = Sphere::from_points(&points).unwrap();
+	/// for point in points {
+	///     assert!(sphere.contains(point));
+	/// }
+	/// let sphere = Sphere::from_points(&[Vector3::one()]).unwrap();
+	/// assert_eq!(Vector3::one(), sphere.center());
+	/// assert_eq!(0.0, sphere.radius());
+	/// assert_eq!(None, Sphere::from_points(&[]));
+	/// ```
+	pub fn from_points(points: &[Vector3]) -> Option<Self> {
+		let first = *points.first()?;
+		let farthest_from = |origin: Vector3| points.iter()
+			.copied()
+			.fold(origin, |farthest, point| {
+				if (point - origin).square_magnitude() > (farthest - origin).square_magnitude() { point }
+				else { farthest }
+			});
+		let a = farthest_from(first);
+		let b = farthest_from(a);
+		let mut center = 0.5 * (a + b);
+		let mut radius = 0.5 * a.distance(b);
+		
+		for point in points {
+			let distance = center.distance(*point);
+			
+			if distance > radius {
+				let new_radius = 0.5 * (radius + distance);
+				
+				center += ((new_radius - radius) / distance) * (*point - center);
+				radius = new_radius;
+			}
+		}
+		
+		// Rounding errors while moving the center can leave the outermost points just outside of the sphere
+		let radius = points.iter().fold(radius, |radius, point| Math::max(radius, center.distance(*point)));
+		
+		Some(Sphere::new(center, radius * (1.0 + f32::EPSILON)))
+	}
+}
+
+/// Properties
+impl Sphere {
+	/// Gets the center of the sphere
+	/// 
+	/// **Returns**: Returns the center of the sphere
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let sphere = Sphere::new(Vector3::one(), 1.0);
+	/// assert_eq!(Vector3::one(), sphere.center());
+	/// ```
+	pub fn center(&self) -> Vector3 { self.center }
+	
+	/// Sets the center of the sphere
+	/// - **value**: The value to set the center to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let mut sphere = Sphere::new(Vector3::one(), 1.0);
+	/// sphere.set_center(Vector3::zero());
+	/// assert_eq!(Vector3::zero(), sphere.center());
+	/// ```
+	pub fn set_center(&mut self, value: Vector3) { self.center = value; }
+	
+	/// Gets the radius of the sphere
+	/// 
+	/// **Returns**: Returns the radius of the sphere
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let sphere = Sphere::new(Vector3::zero(), 3.0);
+	/// assert_eq!(3.0, sphere.radius());
+	/// ```
+	pub fn radius(&self) -> f32 { self.radius }
+	
+	/// Sets the radius of the sphere, negative values are made positive
+	/// - **value**: The value to set the radius to
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let mut sphere = Sphere::new(Vector3::zero(), 3.0);
+	/// sphere.set_radius(-0.5);
+	/// assert_eq!(0.5, sphere.radius());
+	/// ```
+	pub fn set_radius(&mut self, value: f32) { self.radius = Math::abs(value); }
+}
+
+/// Public Methods
+impl Sphere {
+	/// Gets the point within the sphere that is closest to the given point
+	/// - **point**: The point to get the closest point from
+	/// 
+	/// **Returns**: Returns the closest point within the sphere, which is the point itself if it's inside the sphere
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let sphere = Sphere::new(Vector3::zero(), 2.0);
+	/// assert_eq!(Vector3::new(0.0, 2.0, 0.0), sphere.closest_point(Vector3::new(0.0, 5.0, 0.0)));
+	/// assert_eq!(Vector3::new(0.5, 0.5, 0.5), sphere.closest_point(Vector3::new(0.5, 0.5, 0.5)));
+	/// ```
+	pub fn closest_point(&self, point: Vector3) -> Vector3 {
+		self.center + (point - self.center).clamp_magnitude(self.radius)
+	}
+	
+	/// Finds if the point is inside the sphere, including the surface of the sphere
+	/// - **point**: The point to check with
+	/// 
+	/// **Returns**: Returns true if the point is inside the sphere
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let sphere = Sphere::new(Vector3::zero(), 1.0);
+	/// assert!(sphere.contains(Vector3::new(0.5, -0.5, 0.0)));
+	/// assert!(sphere.contains(Vector3::new(0.0, 1.0, 0.0)));
+	/// assert!(!sphere.contains(Vector3::new(1.0, 1.0, 0.0)));
+	/// ```
+	pub fn contains(&self, point: Vector3) -> bool {
+		(point - self.center).square_magnitude() <= self.radius * self.radius
+	}
+	
+	/// Merges the two spheres into the smallest sphere that includes both of them
+	/// - **other**: The other sphere to merge with
+	/// 
+	/// **Returns**: Returns the merged sphere, which is one of the spheres if it already includes the other
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// # use mathx::collision::Sphere;
+	/// let a = Sphere::new(Vector3::zero(), 1.0);
+	/// let b = Sphere::new(Vector3::new(4.0, 0.0, 0.0), 2.0);
+	/// let merged = a.merge(b);
+	/// assert_eq!(Vector3::new(2.5, 0.0, 0.0), merged.center());
+	/// assert_eq!(3.5, merged.radius());
+	/// assert!(merged.contains(Vector3::new(-1.0, 0.0, 0.0)));
+	/// assert!(merged.contains(Vector3::new(6.0, 0.0, 0.0)));
+	/// let inner = Sphere::new(Vector3::new(0.5, 0.0, 0.0), 0.25);
+	/// assert_eq!(a, a.merge(inner));
+	/// assert_eq!(a, inner.merge(a));
+	/// ```
+	pub fn merge(self, other: Sphere) -> Self {
+		let offset = other.center - self.center;
+		let distance = offset.magnitude();
+		
+		if distance + other.radius <= self.radius { return self; }
+		if distance + self.radius <= other.radius { return other; }
+		
+		let radius = 0.5 * (distance + self.radius + other.radius);
+		
+		Sphere::new(self.center + ((radius - self.radius) / distance) * offset, radius)
+	}
+}
+
+#[cfg(not(feature = "no_rays"))]
+impl IRaycast for Sphere {
+	/// Raycasts with the given ray
+	/// - **ray**: The ray to raycast with
+	/// 
+	/// **Returns**: Returns the information on the raycast, with the nearest point in front of the ray and the outward normal at that point
+	/// #### Remarks
+	/// A ray that starts inside of the sphere is considered a hit at a distance of `0.0`, where the point
+	/// is the origin of the ray and the normal is zero since no surface was crossed
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Ray3, interfaces::IRaycast};
+	/// # use mathx::collision::Sphere;
+	/// let sphere = Sphere::new(Vector3::zero(), 1.0);
+	/// let info = sphere.raycast(Ray3::new(Vector3::new(0.0, 0.0, -5.0), Vector3::forward()));
+	/// assert!(info.is_hit());
+	/// assert_eq!(4.0, info.distance());
+	/// assert_eq!(Vector3::new(0.0, 0.0, -1.0), info.point());
+	/// assert_eq!(Vector3::back(), info.normal());
+	/// // Missing the sphere, or pointing away from it
+	/// let info = sphere.raycast(Ray3::new(Vector3::new(0.0, 3.0, -5.0), Vector3::forward()));
+	/// assert!(!info.is_hit());
+	/// let info = sphere.raycast(Ray3::new(Vector3::new(0.0, 0.0, -5.0), Vector3::back()));
+	/// assert!(!info.is_hit());
+	/// // Grazing the side of the sphere
+	/// let info = sphere.raycast(Ray3::new(Vector3::new(1.0, 0.0, -5.0), Vector3::forward()));
+	/// assert!(info.is_hit());
+	/// assert_eq!(5.0, info.distance());
+	/// assert_eq!(Vector3::right(), info.point());
+	/// assert_eq!(Vector3::right(), info.normal());
+	/// // Starting inside of the sphere
+	/// let info = sphere.raycast(Ray3::new(Vector3::new(0.0, 0.5, 0.0), Vector3::up()));
+	/// assert!(info.is_hit());
+	/// assert_eq!(0.0, info.distance());
+	/// assert_eq!(Vector3::new(0.0, 0.5, 0.0), info.point());
+	/// assert_eq!(Vector3::zero(), info.normal());
+	/// ```
+	fn raycast(&self, ray: Ray3) -> RaycastInfo {
+		let offset = ray.origin() - self.center;
+		let c = offset.square_magnitude() - self.radius * self.radius;
+		
+		if c <= 0.0 {
+			return RaycastInfoBuilder::new()
+				.set_hit(true)
+				.set_distance(0.0)
+				.set_normal(Vector3::zero())
+				.set_point(ray.origin())
+				.build();
+		}
+		
+		let a = ray.direction().square_magnitude();
+		let half_b = ray.direction().dot(offset);
+		let discriminant = half_b * half_b - a * c;
+		
+		if Math::approx(a, 0.0) || half_b > 0.0 || discriminant < 0.0 {
+			return RaycastInfo::empty();
+		}
+		
+		let distance = (-half_b - Math::sqrt(discriminant)) / a;
+		let point = ray.get_point(distance);
+		
+		RaycastInfoBuilder::new()
+			.set_hit(true)
+			.set_distance(distance)
+			.set_normal((point - self.center).normalize())
+			.set_point(point)
+			.build()
+	}
+}
+
+unsafe impl Send for Sphere {}
+unsafe impl Sync for Sphere {}
+
+impl Eq for Sphere {}
+impl PartialEq for Sphere {
+	fn eq(&self, other: &Self) -> bool {
+		self.center == other.center
+		&& Math::approx(self.radius, other.radius)
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Sphere {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.write_str(&format!("{{ center: {}, radius: {} }}", self.center, self.radius))
+	}
+}
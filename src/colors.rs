@@ -1,8 +1,30 @@
 
-use crate::Math;
+use crate::{Math, ParseError, interfaces::ApproxEq, parse::parse_floats};
+use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
 
 /// A structure for a color where each channel is a floating point value between 0.0 and 1.0
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// #### Remarks
+/// The arithmetic operators clamp each channel between 0.0 and 1.0. Adding colors brightens them, while
+/// multiplying colors tints one with the other. Adding and subtracting colors keeps the larger alpha of the two colors,
+/// and multiplying or dividing by a number keeps the alpha as is, so that only multiplying two colors changes the alpha
+/// #### Examples
+/// ```
+/// # use mathx::Color;
+/// let red = Color::new(1.0, 0.25, 0.0);
+/// let green = Color::new(0.25, 1.0, 0.5);
+/// assert_eq!(Color::new(1.0, 1.0, 0.5), red + green);
+/// assert_eq!(Color::new(1.0, 1.0, 1.0), red + green + Color::new(0.0, 0.0, 0.75));
+/// assert_eq!(Color::new(0.75, 0.0, 0.0), red - green);
+/// assert_eq!(Color::new(0.5, 0.125, 0.0), red * 0.5);
+/// assert_eq!(Color::new(0.25, 0.25, 0.0), red * green);
+/// let faded = Color::new_alpha(0.0, 0.5, 0.0, 0.25);
+/// assert_eq!(Color::new_alpha(0.0, 0.25, 0.0, 0.25), faded * 0.5);
+/// assert_eq!(Color::new_alpha(0.0, 1.0, 0.0, 0.25), faded / 0.5);
+/// assert_eq!(Color::new(1.0, 0.75, 0.0), red + faded);
+/// assert_eq!(Color::new(1.0, 0.25, 0.0), red * Color::new(1.0, 1.0, 1.0));
+/// assert_eq!(Color::new_alpha(0.0, 0.125, 0.0, 0.25), red * faded);
+/// ```
+#[cfg_attr(all(feature = "serde", not(feature = "serde_hex")), derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy)]
 pub struct Color {
 	/// The red channel of the color
@@ -137,6 +159,50 @@ impl Color {
 			Option::None => Color::new(0.0, 0.0, 0.0),
 		}
 	}
+	
+	/// Creates a new color from hue, saturation, and value (HSV)
+	/// - **h**: The hue of the color in degrees, wrapped to be between 0.0 and 360.0
+	/// - **s**: The saturation of the color, clamped between 0.0 and 1.0
+	/// - **v**: The value (brightness) of the color, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns a new color from the hue, saturation, and value, with the alpha channel set to 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// assert_eq!(Color::new_rgb(255, 0, 0), Color::from_hsv(0.0, 1.0, 1.0));
+	/// assert_eq!(Color::new_rgb(0, 0, 255), Color::from_hsv(240.0, 1.0, 1.0));
+	/// assert_eq!(Color::new_rgb(0, 0, 255), Color::from_hsv(-120.0, 1.0, 1.0));
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), Color::from_hsv(75.0, 0.0, 0.5));
+	/// assert_eq!(Color::new(1.0, 0.5, 0.0), Color::from_hsv(30.0, 2.0, 1.0));
+	/// ```
+	pub fn from_hsv(h: f32, s: f32, v: f32) -> Self {
+		let (r, g, b) = hsv_to_rgb(h, s, v);
+		
+		Color::new(r, g, b)
+	}
+	
+	/// Creates a new color from hue, saturation, and lightness (HSL)
+	/// - **h**: The hue of the color in degrees, wrapped to be between 0.0 and 360.0
+	/// - **s**: The saturation of the color, clamped between 0.0 and 1.0
+	/// - **l**: The lightness of the color, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns a new color from the hue, saturation, and lightness, with the alpha channel set to 1.0
+	/// #### Remarks
+	/// A lightness of 0.0 is always black and a lightness of 1.0 is always white, regardless of the hue and saturation
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// assert_eq!(Color::new_rgb(0, 255, 0), Color::from_hsl(120.0, 1.0, 0.5));
+	/// assert_eq!(Color::new(0.0, 0.0, 0.0), Color::from_hsl(200.0, 0.7, 0.0));
+	/// assert_eq!(Color::new(1.0, 1.0, 1.0), Color::from_hsl(200.0, 0.7, 1.0));
+	/// assert_eq!(Color::new(0.3, 0.3, 0.3), Color::from_hsl(200.0, 0.0, 0.3));
+	/// assert_eq!(Color::new(1.0, 0.5, 0.5), Color::from_hsl(360.0, 1.0, 0.75));
+	/// ```
+	pub fn from_hsl(h: f32, s: f32, l: f32) -> Self {
+		let (r, g, b) = hsl_to_rgb(h, s, l);
+		
+		Color::new(r, g, b)
+	}
 }
 
 /// Properties
@@ -320,6 +386,64 @@ impl Color {
 
 /// Methods
 impl Color {
+	/// Approximately compares this color with the expected color, describing the difference when they diverge
+	/// - **expected**: The color that this color is expected to be
+	/// - **epsilon**: The epsilon (smallest possible difference between numbers) to check with
+	/// 
+	/// **Returns**: Returns `Ok` if every channel is approximately equal, otherwise returns an `Err` naming
+	/// the first channel that diverges and by how much
+	/// #### Remarks
+	/// This allocates the error message and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new(1.0, 0.5, 0.25);
+	/// assert_eq!(Ok(()), color.assert_approx(&Color::new(1.0, 0.5, 0.2501), 0.001));
+	/// let error = color.assert_approx(&Color::new_alpha(1.0, 0.5, 0.25, 0.75), 0.001).unwrap_err();
+	/// assert_eq!("alpha differs by 0.25 (expected 0.75, actual 1)", error);
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn assert_approx(&self, expected: &Color, epsilon: f32) -> Result<(), String> {
+		Math::approx_components(&[
+			("red", expected.r, self.r),
+			("green", expected.g, self.g),
+			("blue", expected.b, self.b),
+			("alpha", expected.a, self.a),
+		], epsilon)
+	}
+	
+	/// Blends this color over the background color using source-over alpha compositing
+	/// - **background**: The color that this color is layered on top of
+	/// 
+	/// **Returns**: Returns the composited color
+	/// #### Remarks
+	/// The output alpha is `a + background_alpha * (1 - a)`, and each channel is the alpha-weighted mix of both colors
+	/// divided by the output alpha. With an opaque background this is simply `a * color + (1 - a) * background`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let red = Color::new(1.0, 0.0, 0.0);
+	/// let blue = Color::new(0.0, 0.0, 1.0);
+	/// assert_eq!(red, red.blend(blue));
+	/// assert_eq!(blue, Color::new_alpha(1.0, 0.0, 0.0, 0.0).blend(blue));
+	/// assert_eq!(Color::new(0.5, 0.0, 0.5), Color::new_alpha(1.0, 0.0, 0.0, 0.5).blend(blue));
+	/// let translucent_blue = Color::new_alpha(0.0, 0.0, 1.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.6666667, 0.0, 0.33333334, 0.75), Color::new_alpha(1.0, 0.0, 0.0, 0.5).blend(translucent_blue));
+	/// ```
+	pub fn blend(self, background: Color) -> Self {
+		let background_weight = background.a * (1.0 - self.a);
+		let alpha = self.a + background_weight;
+		
+		if alpha == 0.0 { return Color::new_alpha(0.0, 0.0, 0.0, 0.0); }
+		
+		Color::new_alpha(
+			(self.r * self.a + background.r * background_weight) / alpha,
+			(self.g * self.a + background.g * background_weight) / alpha,
+			(self.b * self.a + background.b * background_weight) / alpha,
+			alpha
+		)
+	}
+	
 	/// Creates a new color that's a grayscale of this color
 	/// 
 	/// **Returns**: Returns the grayscale version of the color (non-destructive).
@@ -411,16 +535,398 @@ impl Color {
 	/// assert_eq!(156, grayscale);
 	/// ```
 	pub fn get_grayscale_value_as_byte(&self) -> u8  { (((self.r + self.g + self.b) / 3.0) * 255.0) as u8 }
+	
+	/// Creates a new color that's a grayscale of this color using the perceptual luminance, keeping the alpha channel
+	/// 
+	/// **Returns**: Returns the grayscale version of the color where each channel is the luminance
+	/// #### Remarks
+	/// Unlike `to_grayscale` which averages the channels, this weighs each channel by how bright it appears to the eye
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(1.0, 0.0, 0.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.299, 0.299, 0.299, 0.5), color.grayscale());
+	/// let grayscale = Color::new_str("gold").grayscale();
+	/// assert_eq!(grayscale.red(), grayscale.green());
+	/// assert_eq!(grayscale.green(), grayscale.blue());
+	/// ```
+	pub fn grayscale(&self) -> Self {
+		let value = self.luminance();
+		
+		Color::new_alpha(value, value, value, self.a)
+	}
+	
+	/// Gets the perceptual luminance of the color (`0.299 * r + 0.587 * g + 0.114 * b`)
+	/// 
+	/// **Returns**: Returns the luminance of the color, between 0.0 and 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// assert_range!(0.299, Color::new(1.0, 0.0, 0.0).luminance());
+	/// assert_range!(0.587, Color::new(0.0, 1.0, 0.0).luminance());
+	/// assert_range!(0.114, Color::new(0.0, 0.0, 1.0).luminance());
+	/// assert_range!(1.0, Color::new(1.0, 1.0, 1.0).luminance());
+	/// ```
+	pub fn luminance(&self) -> f32 { 0.299 * self.r + 0.587 * self.g + 0.114 * self.b }
+	
+	/// Creates a new color with the red, green, and blue channels inverted, keeping the alpha channel
+	/// 
+	/// **Returns**: Returns the inverted color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// assert_eq!(Color::new_rgb(255, 255, 255), Color::new_rgb(0, 0, 0).invert());
+	/// let color = Color::new_alpha(0.2, 0.6, 0.9, 0.3);
+	/// assert_eq!(Color::new_alpha(0.8, 0.4, 0.1, 0.3), color.invert());
+	/// assert_eq!(color, color.invert().invert());
+	/// ```
+	pub fn invert(&self) -> Self { Color::new_alpha(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a) }
+	
+	/// Linearly interpolates between this and the other color, including the alpha channel
+	/// - **rhs**: The other color to end from
+	/// - **t**: The ratio value to interpolate between both colors. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let black = Color::new(0.0, 0.0, 0.0);
+	/// let white = Color::new(1.0, 1.0, 1.0);
+	/// assert_eq!(Color::new(0.5, 0.5, 0.5), black.lerp(white, 0.5));
+	/// assert_eq!(black, black.lerp(white, 0.0));
+	/// assert_eq!(white, black.lerp(white, 1.0));
+	/// assert_eq!(white, black.lerp(white, 2.0));
+	/// let a = Color::new_alpha(1.0, 0.0, 0.0, 0.0);
+	/// let b = Color::new_alpha(0.0, 0.0, 1.0, 1.0);
+	/// assert_eq!(Color::new_alpha(0.75, 0.0, 0.25, 0.25), a.lerp(b, 0.25));
+	/// ```
+	pub fn lerp(self, rhs: Color, t: f32) -> Self { self.lerp_unclamped(rhs, Math::clamp(t, 0.0, 1.0)) }
+	
+	/// Linearly interpolates between this and the other color, including the alpha channel (not clamped)
+	/// - **rhs**: The other color to end from
+	/// - **t**: The ratio value to interpolate between both colors
+	/// 
+	/// **Returns**: Returns the interpolated color, with each channel clamped between 0.0 and 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let a = Color::new(0.25, 0.5, 0.75);
+	/// let b = Color::new(0.5, 0.5, 0.5);
+	/// assert_eq!(Color::new(0.75, 0.5, 0.25), a.lerp_unclamped(b, 2.0));
+	/// assert_eq!(Color::new(1.0, 0.5, 0.0), a.lerp_unclamped(b, 4.0));
+	/// ```
+	pub fn lerp_unclamped(self, rhs: Color, t: f32) -> Self {
+		Color::new_alpha(
+			Math::lerp_unclamped(self.r, rhs.r, t),
+			Math::lerp_unclamped(self.g, rhs.g, t),
+			Math::lerp_unclamped(self.b, rhs.b, t),
+			Math::lerp_unclamped(self.a, rhs.a, t)
+		)
+	}
+	
+	/// Multiplies each channel of this color with the matching channel of the other color (including the alpha channel), tinting this color.
+	/// This is the same as `color * rhs`
+	/// - **rhs**: The other color to multiply with
+	/// 
+	/// **Returns**: Returns the multiplied color
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new(0.8, 0.6, 0.4);
+	/// let tint = Color::new(1.0, 0.5, 0.25);
+	/// assert_eq!(Color::new(0.8, 0.3, 0.1), color.multiply(tint));
+	/// assert_eq!(Color::new(0.8, 0.3, 0.1), color * tint);
+	/// assert_eq!(color, color * Color::new(1.0, 1.0, 1.0));
+	/// assert_eq!(Color::new(0.0, 0.0, 0.0), color * Color::new(0.0, 0.0, 0.0));
+	/// let faded = Color::new_alpha(1.0, 1.0, 1.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.8, 0.6, 0.4, 0.5), color * faded);
+	/// ```
+	pub fn multiply(self, rhs: Color) -> Self {
+		Color::new_alpha(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b, self.a * rhs.a)
+	}
+	
+	/// Finds the color within the palette that's perceptually nearest to this color
+	/// - **palette**: The list of colors to pick from
+	/// 
+	/// **Returns**: Returns the nearest color from the palette, returns this color if the palette is empty
+	/// #### Remarks
+	/// The distance is the squared difference of each channel weighted by how sensitive the eye is to it
+	/// (0.299 for red, 0.587 for green, and 0.114 for blue). The alpha channel is not taken into account
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let palette = [Color::new(0.0, 0.0, 0.0), Color::new(1.0, 1.0, 1.0), Color::new(1.0, 0.0, 0.0), Color::new(0.0, 0.0, 1.0)];
+	/// assert_eq!(Color::new(1.0, 0.0, 0.0), Color::new(0.9, 0.2, 0.1).nearest_in_palette(&palette));
+	/// assert_eq!(Color::new(1.0, 1.0, 1.0), Color::new(0.7, 0.8, 0.6).nearest_in_palette(&palette));
+	/// assert_eq!(Color::new(0.0, 0.0, 1.0), Color::new(0.1, 0.1, 0.9).nearest_in_palette(&palette));
+	/// assert_eq!(Color::new(0.3, 0.3, 0.3), Color::new(0.3, 0.3, 0.3).nearest_in_palette(&[]));
+	/// ```
+	pub fn nearest_in_palette(&self, palette: &[Color]) -> Self {
+		let mut nearest = *self;
+		let mut nearest_distance = f32::MAX;
+		
+		for color in palette {
+			let r = self.r - color.r;
+			let g = self.g - color.g;
+			let b = self.b - color.b;
+			let distance = 0.299 * r * r + 0.587 * g * g + 0.114 * b * b;
+			
+			if distance < nearest_distance {
+				nearest = *color;
+				nearest_distance = distance;
+			}
+		}
+		
+		nearest
+	}
+	
+	/// Quantizes the color down to the given bit depth for each of the red, green, and blue channels
+	/// - **bits_per_channel**: The number of bits each channel gets, clamped between 1 and 16
+	/// 
+	/// **Returns**: Returns the quantized color, keeping the alpha channel as is
+	/// #### Remarks
+	/// With `n` bits a channel can only hold `2^n` levels, so each channel gets rounded to the nearest
+	/// multiple of `1 / (2^n - 1)`. Using 1 bit snaps each channel to either 0.0 or 1.0 (the corners of the color cube),
+	/// while using 8 bits matches the precision of a byte
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(0.7, 0.2, 0.5, 0.3);
+	/// assert_eq!(Color::new_alpha(1.0, 0.0, 1.0, 0.3), color.quantize_to_bits(1));
+	/// assert_eq!(Color::new_alpha(0.6666667, 0.33333334, 0.6666667, 0.3), color.quantize_to_bits(2));
+	/// let color = Color::new_rgb(51, 102, 204);
+	/// assert_eq!(color, color.quantize_to_bits(8));
+	/// ```
+	pub fn quantize_to_bits(&self, bits_per_channel: u8) -> Self {
+		let bits = bits_per_channel.clamp(1, 16);
+		let max = ((1u32 << bits) - 1) as f32;
+		
+		Color::new_alpha(
+			Math::round(self.r * max) / max,
+			Math::round(self.g * max) / max,
+			Math::round(self.b * max) / max,
+			self.a
+		)
+	}
+	
+	/// Converts the color into hue, saturation, and value (HSV), ignoring the alpha channel
+	/// 
+	/// **Returns**: Returns a tuple of the hue in degrees (between 0.0 and 360.0), the saturation, and the value
+	/// #### Remarks
+	/// Gray colors (including black and white) have no hue, so the hue is returned as 0.0 for them
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// assert_eq!((0.0, 1.0, 1.0), Color::new_rgb(255, 0, 0).to_hsv());
+	/// assert_eq!((0.0, 0.0, 0.5), Color::new(0.5, 0.5, 0.5).to_hsv());
+	/// let (h, s, v) = Color::new(0.2, 0.4, 0.8).to_hsv();
+	/// assert_range!(220.0, h, 0.001);
+	/// assert_range!(0.75, s);
+	/// assert_range!(0.8, v);
+	/// assert_eq!(Color::new(0.2, 0.4, 0.8), Color::from_hsv(h, s, v));
+	/// ```
+	pub fn to_hsv(&self) -> (f32, f32, f32) { rgb_to_hsv(self.r, self.g, self.b) }
+	
+	/// Converts the color into a hex code string, ignoring the alpha channel
+	/// 
+	/// **Returns**: Returns the hex code string formatted as `#RRGGBB`
+	/// #### Remarks
+	/// This allocates the string and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_rgb(255, 99, 71);
+	/// assert_eq!("#FF6347", color.to_hex());
+	/// assert_eq!(color, Color::new_str(&color.to_hex()));
+	/// assert_eq!("#000000", Color::new_rgba(0, 0, 0, 0).to_hex());
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn to_hex(&self) -> String {
+		format!("#{:02X}{:02X}{:02X}", self.red_as_byte(), self.green_as_byte(), self.blue_as_byte())
+	}
+	
+	/// Converts the color into a hex code string, including the alpha channel
+	/// 
+	/// **Returns**: Returns the hex code string formatted as `#RRGGBBAA`
+	/// #### Remarks
+	/// This allocates the string and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_rgba(90, 156, 164, 221);
+	/// assert_eq!("#5A9CA4DD", color.to_hex_alpha());
+	/// assert_eq!(color, Color::new_str(&color.to_hex_alpha()));
+	/// assert_eq!("#FF6347FF", Color::new_rgb(255, 99, 71).to_hex_alpha());
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn to_hex_alpha(&self) -> String {
+		format!(
+			"#{:02X}{:02X}{:02X}{:02X}",
+			self.red_as_byte(),
+			self.green_as_byte(),
+			self.blue_as_byte(),
+			self.alpha_as_byte()
+		)
+	}
+	
+	/// Converts the color into hue, saturation, and lightness (HSL), ignoring the alpha channel
+	/// 
+	/// **Returns**: Returns a tuple of the hue in degrees (between 0.0 and 360.0), the saturation, and the lightness
+	/// #### Remarks
+	/// Gray colors (including black and white) have no hue nor saturation, so both are returned as 0.0 for them
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Color,Math,assert_range};
+	/// assert_eq!((120.0, 1.0, 0.5), Color::new_rgb(0, 255, 0).to_hsl());
+	/// assert_eq!((0.0, 0.0, 1.0), Color::new(1.0, 1.0, 1.0).to_hsl());
+	/// assert_eq!((0.0, 0.0, 0.0), Color::new(0.0, 0.0, 0.0).to_hsl());
+	/// let color = Color::new_alpha(0.2, 0.4, 0.8, 0.5);
+	/// let (h, s, l) = color.to_hsl();
+	/// assert_range!(220.0, h, 0.001);
+	/// assert_range!(0.6, s);
+	/// assert_range!(0.5, l);
+	/// assert_eq!(Color::new(0.2, 0.4, 0.8), Color::from_hsl(h, s, l));
+	/// assert_eq!(0.5, color.alpha());
+	/// ```
+	pub fn to_hsl(&self) -> (f32, f32, f32) { rgb_to_hsl(self.r, self.g, self.b) }
+	
+	/// Creates a new color with the same saturation, value, and alpha but with a different hue
+	/// - **degrees**: The hue to set in degrees, wrapped to be between 0.0 and 360.0
+	/// 
+	/// **Returns**: Returns the color with the new hue
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(1.0, 0.0, 0.0, 0.5);
+	/// assert_eq!(Color::new_alpha(0.0, 1.0, 0.0, 0.5), color.with_hue(120.0));
+	/// assert_eq!(Color::new_alpha(0.0, 0.0, 1.0, 0.5), color.with_hue(-120.0));
+	/// ```
+	pub fn with_hue(self, degrees: f32) -> Self {
+		let (_, saturation, value) = rgb_to_hsv(self.r, self.g, self.b);
+		let (r, g, b) = hsv_to_rgb(degrees, saturation, value);
+		
+		Color::new_alpha(r, g, b, self.a)
+	}
+	
+	/// Creates a new color with the same hue, value, and alpha but with a different saturation
+	/// - **s**: The saturation to set, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the color with the new saturation
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new(0.8, 0.4, 0.2);
+	/// assert_eq!(Color::new(0.8, 0.8, 0.8), color.with_saturation(0.0));
+	/// assert_eq!(Color::new(0.8, 0.26666668, 0.0), color.with_saturation(1.0));
+	/// ```
+	pub fn with_saturation(self, s: f32) -> Self {
+		let (hue, _, value) = rgb_to_hsv(self.r, self.g, self.b);
+		let (r, g, b) = hsv_to_rgb(hue, s, value);
+		
+		Color::new_alpha(r, g, b, self.a)
+	}
+	
+	/// Creates a new color with the same hue, saturation, and alpha but with a different value (brightness)
+	/// - **v**: The value to set, clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the color with the new value
+	/// #### Examples
+	/// ```
+	/// # use mathx::Color;
+	/// let color = Color::new_alpha(1.0, 0.5, 0.0, 0.25);
+	/// assert_eq!(Color::new_alpha(0.5, 0.25, 0.0, 0.25), color.with_value(0.5));
+	/// assert_eq!(Color::new_alpha(0.0, 0.0, 0.0, 0.25), color.with_value(0.0));
+	/// ```
+	pub fn with_value(self, v: f32) -> Self {
+		let (hue, saturation, _) = rgb_to_hsv(self.r, self.g, self.b);
+		let (r, g, b) = hsv_to_rgb(hue, saturation, v);
+		
+		Color::new_alpha(r, g, b, self.a)
+	}
+}
+
+// Default
+/// Gets opaque black, the same as `Color::new(0.0, 0.0, 0.0)`
+/// #### Examples
+/// ```
+/// # use mathx::Color;
+/// assert_eq!(Color::new(0.0, 0.0, 0.0), Color::default());
+/// ```
+impl Default for Color {
+	fn default() -> Self { Color::new(0.0, 0.0, 0.0) }
 }
 
 // Equates
 impl Eq for Color {}
 impl PartialEq for Color {
-	fn eq(&self, other: &Self) -> bool {
-		Math::approx(self.r, other.r)
-		&& Math::approx(self.g, other.g)
-		&& Math::approx(self.b, other.b)
-		&& Math::approx(self.a, other.a)
+	fn eq(&self, other: &Self) -> bool { self.approx_eq(other) }
+}
+
+/// Approximately compares each component of the color
+/// #### Examples
+/// ```
+/// # use mathx::Color;
+/// # use mathx::interfaces::ApproxEq;
+/// let a = Color::new(1.0, 0.5, 0.25);
+/// let b = Color::new(1.0, 0.5001, 0.25);
+/// assert!(a.approx_eq_eps(&b, 0.001));
+/// assert!(!a.approx_eq(&b));
+/// ```
+impl ApproxEq for Color {
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool {
+		Math::approx_epsilon(self.r, other.r, epsilon)
+		&& Math::approx_epsilon(self.g, other.g, epsilon)
+		&& Math::approx_epsilon(self.b, other.b, epsilon)
+		&& Math::approx_epsilon(self.a, other.a, epsilon)
+	}
+}
+
+// Serialization
+/// Serializes the color as a `#RRGGBBAA` hex code string when using the `serde_hex` feature
+#[cfg(feature = "serde_hex")]
+impl serde::Serialize for Color {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+		let bytes = [self.red_as_byte(), self.green_as_byte(), self.blue_as_byte(), self.alpha_as_byte()];
+		let mut hex = [b'#'; 9];
+		
+		for (i, byte) in bytes.iter().enumerate() {
+			hex[2 * i + 1] = DIGITS[(byte >> 4) as usize];
+			hex[2 * i + 2] = DIGITS[(byte & 0x0F) as usize];
+		}
+		
+		match core::str::from_utf8(&hex) {
+			Ok(value) => serializer.serialize_str(value),
+			Err(_) => Err(serde::ser::Error::custom("could not write the hex code of the color")),
+		}
+	}
+}
+
+/// Deserializes the color from a hex code or known name string when using the `serde_hex` feature
+#[cfg(feature = "serde_hex")]
+impl<'de> serde::Deserialize<'de> for Color {
+	fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_str(ColorHexVisitor)
+	}
+}
+
+/// Visits the hex code or known name string of a color when deserializing
+#[cfg(feature = "serde_hex")]
+struct ColorHexVisitor;
+
+#[cfg(feature = "serde_hex")]
+impl serde::de::Visitor<'_> for ColorHexVisitor {
+	type Value = Color;
+	
+	fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+		formatter.write_str("a hex code such as #RRGGBBAA or a known color name")
+	}
+	
+	fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+		match from_known_name(value) {
+			Some(color) => Ok(color),
+			None => Err(E::invalid_value(serde::de::Unexpected::Str(value), &self)),
+		}
 	}
 }
 
@@ -432,6 +938,156 @@ impl std::fmt::Display for Color {
 	}
 }
 
+// Parsing
+/// Parses the color from either a known name or hex code (the same as `Color::new_str`),
+/// or from three or four comma-separated numbers between 0.0 and 1.0 that can be surrounded by parentheses
+/// #### Remarks
+/// Unlike `Color::new_str`, this returns an error instead of black when the text is not a valid color.
+/// This accepts the `Display` output and the `to_hex` output, so both can be parsed back into the same color
+/// #### Examples
+/// ```
+/// # use mathx::{Color,ParseError};
+/// let tomato = Color::new_rgb(255, 99, 71);
+/// assert_eq!(Ok(tomato), "tomato".parse());
+/// assert_eq!(Ok(tomato), "#FF6347".parse());
+/// assert_eq!(Ok(Color::new_alpha(1.0, 0.5, 0.25, 0.5)), "(1, 0.5, 0.25, 0.5)".parse());
+/// assert_eq!(Ok(Color::new(1.0, 0.5, 0.25)), "1, 0.5, 0.25".parse());
+/// # #[cfg(not(feature = "no_std"))] {
+/// assert_eq!(Ok(tomato), tomato.to_hex().parse());
+/// assert_eq!(Ok(tomato), tomato.to_string().parse());
+/// # }
+/// assert_eq!(Err(ParseError::UnknownColor), "not a color".parse::<Color>());
+/// assert_eq!(Err(ParseError::InvalidNumber { index: 1 }), "(1, green, 0)".parse::<Color>());
+/// assert_eq!(Err(ParseError::WrongCount { expected: 4, found: 2 }), "1, 0.5".parse::<Color>());
+/// ```
+impl core::str::FromStr for Color {
+	type Err = ParseError;
+	
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		if let Some(color) = from_known_name(text.trim()) { return Ok(color); }
+		if !text.contains(',') { return Err(ParseError::UnknownColor); }
+		
+		match parse_floats(text, ["", "", ""]) {
+			Ok([r, g, b]) => Ok(Color::new(r, g, b)),
+			Err(ParseError::WrongCount { .. }) => {
+				let [r, g, b, a] = parse_floats(text, ["", "", "", ""])?;
+				
+				Ok(Color::new_alpha(r, g, b, a))
+			},
+			Err(error) => Err(error),
+		}
+	}
+}
+
+// Arithmetic
+impl AddSubArithmetic<Color> for Color {
+	type Output = Color;
+	fn add_other(self, rhs: Color) -> Self::Output {
+		Color::new_alpha(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b, Math::max(self.a, rhs.a))
+	}
+	fn add_assign_other(&mut self, rhs: Color) {
+		*self = self.add_other(rhs);
+	}
+	fn subtract_other(self, rhs: Color) -> Self::Output {
+		Color::new_alpha(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b, Math::max(self.a, rhs.a))
+	}
+	fn subtract_assign_other(&mut self, rhs: Color) {
+		*self = self.subtract_other(rhs);
+	}
+}
+
+impl MulDivScalar for Color {
+	type Output = Color;
+	fn multiply_scalar(self, rhs: f32) -> Self::Output {
+		Color::new_alpha(rhs * self.r, rhs * self.g, rhs * self.b, self.a)
+	}
+	fn multiply_assign_scalar(&mut self, rhs: f32) {
+		*self = self.multiply_scalar(rhs);
+	}
+	fn divide_scalar(self, rhs: f32) -> Self::Output {
+		if rhs == 0.0 { return Color::new_alpha(0.0, 0.0, 0.0, self.a); }
+		Color::new_alpha(self.r / rhs, self.g / rhs, self.b / rhs, self.a)
+	}
+	fn divide_assign_scalar(&mut self, rhs: f32) {
+		*self = self.divide_scalar(rhs);
+	}
+	fn reciprocal_scalar(self, rhs: f32) -> Self::Output {
+		Color::new_alpha(
+			if self.r != 0.0 { rhs / self.r } else { 0.0 },
+			if self.g != 0.0 { rhs / self.g } else { 0.0 },
+			if self.b != 0.0 { rhs / self.b } else { 0.0 },
+			self.a,
+		)
+	}
+}
+
+use_impl_ops!();
+impl_add!(Color);
+impl_sub!(Color);
+impl_mul!(Color);
+impl_mul!(Color, Color => Color: multiply);
+impl_div!(Color);
+
+fn rgb_to_hsv(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+	let max = Math::max(r, Math::max(g, b));
+	let min = Math::min(r, Math::min(g, b));
+	let delta = max - min;
+	let saturation = if max == 0.0 { 0.0 } else { delta / max };
+	
+	(get_hue(r, g, b, max, delta), saturation, max)
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (f32, f32, f32) {
+	let s = Math::clamp(s, 0.0, 1.0);
+	let v = Math::clamp(v, 0.0, 1.0);
+	let chroma = v * s;
+	
+	chroma_to_rgb(h, chroma, v - chroma)
+}
+
+fn rgb_to_hsl(r: f32, g: f32, b: f32) -> (f32, f32, f32) {
+	let max = Math::max(r, Math::max(g, b));
+	let min = Math::min(r, Math::min(g, b));
+	let delta = max - min;
+	let lightness = 0.5 * (max + min);
+	let saturation = if delta == 0.0 { 0.0 } else { delta / (1.0 - Math::abs(2.0 * lightness - 1.0)) };
+	
+	(get_hue(r, g, b, max, delta), Math::clamp(saturation, 0.0, 1.0), lightness)
+}
+
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+	let s = Math::clamp(s, 0.0, 1.0);
+	let l = Math::clamp(l, 0.0, 1.0);
+	let chroma = (1.0 - Math::abs(2.0 * l - 1.0)) * s;
+	
+	chroma_to_rgb(h, chroma, l - 0.5 * chroma)
+}
+
+fn get_hue(r: f32, g: f32, b: f32, max: f32, delta: f32) -> f32 {
+	if delta == 0.0 { return 0.0; }
+	
+	let hue = if max == r { 60.0 * ((g - b) / delta) }
+		else if max == g { 60.0 * ((b - r) / delta + 2.0) }
+		else { 60.0 * ((r - g) / delta + 4.0) };
+	
+	if hue < 0.0 { hue + 360.0 } else { hue }
+}
+
+fn chroma_to_rgb(h: f32, chroma: f32, m: f32) -> (f32, f32, f32) {
+	let sector = (h - 360.0 * Math::floor(h / 360.0)) / 60.0;
+	let x = chroma * (1.0 - Math::abs(sector % 2.0 - 1.0));
+	let (r, g, b) = match sector as i32 {
+		1 => (x, chroma, 0.0),
+		2 => (0.0, chroma, x),
+		3 => (0.0, x, chroma),
+		4 => (x, 0.0, chroma),
+		5 => (chroma, 0.0, x),
+		_ => (chroma, x, 0.0),
+	};
+	
+	(r + m, g + m, b + m)
+}
+
 fn from_hex(hex: &str) -> Option<Color> {
 	if !hex.starts_with("#") { return Option::None; }
 	
@@ -0,0 +1,20 @@
+
+/// A wrapper that compares and hashes the wrapped value by the exact bits of it's components,
+/// so that vectors and quaternions can be used as keys within a `HashMap` or `HashSet`
+/// #### Remarks
+/// The regular `PartialEq` of the wrapped types approximates each component, which can't be hashed
+/// consistently. This compares the bits instead, which means `0.0` and `-0.0` are not equal while
+/// `NaN` is equal to itself if the bits match
+/// #### Examples
+/// ```
+/// # #[cfg(not(feature = "no_vectors"))] {
+/// # use std::collections::HashMap;
+/// # use mathx::{Exact,Vector3};
+/// let mut map = HashMap::new();
+/// map.insert(Exact(Vector3::new(1.0, 2.0, 3.0)), "cell");
+/// assert_eq!(Some(&"cell"), map.get(&Exact(Vector3::new(1.0, 2.0, 3.0))));
+/// assert_eq!(None, map.get(&Exact(Vector3::new(1.0, 2.0, 3.0000002))));
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Exact<T>(pub T);
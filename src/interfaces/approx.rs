@@ -0,0 +1,51 @@
+
+use crate::Math;
+
+/// Approximately compares values, allowing generic code to compare any of the crate's types
+/// #### Examples
+/// ```
+/// # #[cfg(not(feature = "no_vectors"))]
+/// # use mathx::Vector3;
+/// # #[cfg(not(feature = "no_quaternions"))]
+/// # use mathx::Quaternion;
+/// # #[cfg(not(feature = "no_colors"))]
+/// # use mathx::Color;
+/// # use mathx::interfaces::ApproxEq;
+/// fn all_close<T: ApproxEq>(expected: &[T], actual: &[T], epsilon: f32) -> bool {
+///     expected.iter().zip(actual).all(|(a, b)| a.approx_eq_eps(b, epsilon))
+/// }
+/// assert!(all_close(&[1.0, 2.0], &[1.0001, 1.9999], 0.001));
+/// assert!(!all_close(&[1.0, 2.0], &[1.0001, 1.9], 0.001));
+/// # #[cfg(not(feature = "no_vectors"))]
+/// assert!(all_close(&[Vector3::one()], &[Vector3::new(1.0, 1.0, 1.0001)], 0.001));
+/// # #[cfg(not(feature = "no_quaternions"))]
+/// assert!(all_close(&[Quaternion::identity()], &[Quaternion::new(1.0, 0.0, 0.0, 0.0)], 0.001));
+/// # #[cfg(not(feature = "no_colors"))]
+/// assert!(!all_close(&[Color::new(1.0, 0.0, 0.0)], &[Color::new(0.0, 0.0, 1.0)], 0.001));
+/// ```
+pub trait ApproxEq {
+	/// Finds if the two values are approximately close to each other. Checks with epsilon = 0.000001
+	/// - **other**: The other value to check with
+	/// 
+	/// **Returns**: Returns true if the two values are approximately close to each other
+	fn approx_eq(&self, other: &Self) -> bool { self.approx_eq_eps(other, 0.000001) }
+	
+	/// Finds if the two values are approximately close to each other, provided the epsilon
+	/// - **other**: The other value to check with
+	/// - **epsilon**: The epsilon (smallest possible difference between each component) to check with
+	/// 
+	/// **Returns**: Returns true if the two values are approximately close to each other
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool;
+}
+
+/// Approximately compares the numbers, the same as `Math::approx_epsilon`
+/// #### Examples
+/// ```
+/// # use mathx::interfaces::ApproxEq;
+/// assert!(1.20000001.approx_eq(&1.2));
+/// assert!(1.2001.approx_eq_eps(&1.2, 0.001));
+/// assert!(!1.21.approx_eq_eps(&1.2, 0.001));
+/// ```
+impl ApproxEq for f32 {
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool { Math::approx_epsilon(*self, *other, epsilon) }
+}
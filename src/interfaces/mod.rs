@@ -1,5 +1,8 @@
 
-#[cfg(not(all(feature = "no_vectors", feature = "no_rays")))]
+mod approx;
+pub use approx::*;
+
+#[cfg(not(any(feature = "no_vectors", feature = "no_rays", feature = "no_collision")))]
 mod collision;
-#[cfg(not(all(feature = "no_vectors", feature = "no_rays")))]
+#[cfg(not(any(feature = "no_vectors", feature = "no_rays", feature = "no_collision")))]
 pub use collision::*;
@@ -2,11 +2,20 @@
 
 mod math;
 pub use math::Math;
+mod math_f64;
+pub use math_f64::MathF64;
+mod exact;
+pub use exact::Exact;
+mod stats;
+pub use stats::RunningAverage;
 pub mod interfaces;
 
-#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions")))]
+#[cfg(not(any(feature = "no_std", all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors"))))]
+mod assertions;
+
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_matrices", feature = "no_colors")))]
 mod arithmetic;
-#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions")))]
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_matrices", feature = "no_colors")))]
 pub(crate) use arithmetic::*;
 
 #[cfg(not(feature = "no_quaternions"))]
@@ -14,19 +23,29 @@ mod quaternions;
 #[cfg(not(feature = "no_quaternions"))]
 pub use quaternions::Quaternion;
 
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
+mod parse;
+#[cfg(not(all(feature = "no_vectors", feature = "no_quaternions", feature = "no_colors")))]
+pub use parse::ParseError;
+
 #[cfg(not(feature = "no_vectors"))]
 mod vectors;
 #[cfg(not(feature = "no_vectors"))]
-pub use vectors::{Vector3, Vector2};
+pub use vectors::{Vector4, Vector3, Vector2};
 
-#[cfg(not(all(feature = "no_rays", feature = "no_vectors")))]
+#[cfg(not(feature = "no_matrices"))]
+mod matrices;
+#[cfg(not(feature = "no_matrices"))]
+pub use matrices::{Matrix3x3, Matrix4x4};
+
+#[cfg(not(any(feature = "no_rays", feature = "no_vectors")))]
 mod rays;
-#[cfg(not(all(feature = "no_rays", feature = "no_vectors")))]
+#[cfg(not(any(feature = "no_rays", feature = "no_vectors")))]
 pub use rays::{Ray2, Ray3};
 
-#[cfg(not(all(feature = "no_planes", feature = "no_vectors")))]
+#[cfg(not(any(feature = "no_planes", feature = "no_vectors")))]
 mod plane;
-#[cfg(not(all(feature = "no_planes", feature = "no_vectors")))]
+#[cfg(not(any(feature = "no_planes", feature = "no_vectors")))]
 pub use plane::Plane;
 
 #[cfg(not(feature = "no_collision"))]
@@ -35,4 +54,9 @@ pub mod collision;
 #[cfg(not(feature = "no_colors"))]
 mod colors;
 #[cfg(not(feature = "no_colors"))]
-pub use colors::Color;
\ No newline at end of file
+pub use colors::Color;
+
+#[cfg(feature = "random")]
+mod random;
+#[cfg(feature = "random")]
+pub use random::Random;
@@ -34,12 +34,11 @@ impl Math {
 	/// assert_eq!(10.0, value);
 	/// let value = Math::abs(-0.0);
 	/// assert_eq!(0.0, value);
+	/// assert!(value.is_sign_positive());
 	/// ```
 	pub fn abs(value: f32) -> f32 {
 		#[cfg(not(feature = "no_std"))] { value.abs() }
-		#[cfg(feature = "no_std")] {
-			if value < 0.0 { -value } else { value }
-		}
+		#[cfg(feature = "no_std")] { f32::from_bits(value.to_bits() & 0x7fff_ffff) }
 	}
 	
 	/// Gets the absolute value of the number
@@ -92,6 +91,43 @@ impl Math {
 		Math::abs(a - b) < epsilon
 	}
 	
+	/// Finds if the two floating point numbers are approximately close to each other relative to their size.
+	/// Checks with epsilon = 0.000001 scaled by the largest magnitude of the two numbers
+	/// - **a**: The first number to check with
+	/// - **b**: The second number to check with
+	/// 
+	/// **Returns**: Returns true if the two values are approximately close to each other
+	/// #### Remarks
+	/// Use `approx` (an absolute check) when the numbers are close to zero, since the scaled epsilon shrinks
+	/// to nothing there. Use `approx_relative` when the numbers are large, where `f32` can no longer
+	/// hold enough decimal places for `approx` to ever find them close
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// assert!(Math::approx_relative(100000.0, 100000.01));
+	/// assert!(!Math::approx(100000.0, 100000.01));
+	/// assert!(!Math::approx_relative(100000.0, 100001.0));
+	/// assert!(Math::approx_relative(0.0, 0.0));
+	/// assert!(!Math::approx_relative(0.0, 0.0000001));
+	/// assert!(Math::approx(0.0, 0.0000001));
+	/// ```
+	pub fn approx_relative(a: f32, b: f32) -> bool {
+		Math::abs(a - b) <= 0.000001 * Math::max(Math::abs(a), Math::abs(b))
+	}
+	
+	/// Finds if the floating point number is approximately zero. Checks with epsilon = 0.000001
+	/// - **value**: The number to check with
+	/// 
+	/// **Returns**: Returns true if the value is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// assert!(Math::approx_zero(0.0000001));
+	/// assert!(Math::approx_zero(-0.0000001));
+	/// assert!(!Math::approx_zero(0.001));
+	/// ```
+	pub fn approx_zero(value: f32) -> bool { Math::abs(value) < 0.000001 }
+	
 	/// Computes the arc cosine (a.k.a. inverse cosine) with the provided value
 	/// - **value**: The value to compute the arc cosine with, must be within -1 and 1
 	/// 
@@ -367,6 +403,9 @@ impl Math {
 	/// - **x**: The x value to compute the arc tangent with
 	/// 
 	/// **Returns**: Returns the angle at with the two values divided exists in radians
+	/// #### Remarks
+	/// When both values are zero, this follows the signs of the zeroes the same as `f32::atan2`:
+	/// returning `0.0` (with the sign of `y`) for a positive `x` and `PI` (with the sign of `y`) for a negative `x`
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Math,assert_range};
@@ -384,10 +423,22 @@ impl Math {
 	/// assert_range!(-1.3734008, value);
 	/// let value = Math::atan2(-1.0, 5.0);
 	/// assert_range!(-0.19739556, value);
+	/// let value = Math::atan2(0.0, 0.0);
+	/// assert!(value == 0.0 && value.is_sign_positive());
+	/// let value = Math::atan2(-0.0, 0.0);
+	/// assert!(value == 0.0 && value.is_sign_negative());
+	/// let value = Math::atan2(0.0, -0.0);
+	/// assert_range!(Math::PI, value);
+	/// let value = Math::atan2(-0.0, -0.0);
+	/// assert_range!(-Math::PI, value);
 	/// ```
 	pub fn atan2(y: f32, x: f32) -> f32 {
 		#[cfg(not(feature = "no_std"))] { y.atan2(x) }
 		#[cfg(feature = "no_std")] {
+			if x == 0.0 && y == 0.0 {
+				return if x.is_sign_negative() { Math::copysign(Math::PI, y) } else { y };
+			}
+			
 			let mut a = Math::abs(x);
 			let mut b = Math::abs(y);
 			let mut c = Math::max(a, b);
@@ -437,6 +488,45 @@ impl Math {
 	/// ```
 	pub fn atan2_deg(y: f32, x: f32) -> f32 { Math::RAD_TO_DEG * Math::atan2(y, x) }
 	
+	/// Gets the exponent of the smallest power of two that is greater than or equal to the given value
+	/// - **value**: The value to get the exponent for
+	/// 
+	/// **Returns**: Returns the rounded up log in base 2 of the value, returns 0 for both 0 and 1
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::bit_ceil_log2(1024);
+	/// assert_eq!(10, value);
+	/// let value = Math::bit_ceil_log2(1025);
+	/// assert_eq!(11, value);
+	/// let value = Math::bit_ceil_log2(3);
+	/// assert_eq!(2, value);
+	/// let value = Math::bit_ceil_log2(1);
+	/// assert_eq!(0, value);
+	/// let value = Math::bit_ceil_log2(0);
+	/// assert_eq!(0, value);
+	/// ```
+	pub fn bit_ceil_log2(value: u32) -> u32 {
+		if value <= 1 { 0 }
+		else { u32::BITS - (value - 1).leading_zeros() }
+	}
+	
+	/// Counts the number of bits that are set within the given value
+	/// - **value**: The value to count the bits with
+	/// 
+	/// **Returns**: Returns the number of ones within the binary representation of the value
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::bit_count(0b1011);
+	/// assert_eq!(3, value);
+	/// let value = Math::bit_count(0);
+	/// assert_eq!(0, value);
+	/// let value = Math::bit_count(u32::MAX);
+	/// assert_eq!(32, value);
+	/// ```
+	pub fn bit_count(value: u32) -> u32 { value.count_ones() }
+	
 	/// Gets the smallest integer number that is greater than or equal to the given number
 	/// - **value**: The value to get the ceiling with
 	/// 
@@ -466,6 +556,62 @@ impl Math {
 		}
 	}
 	
+	/// Rounds the value up to the smallest multiple of the increment that is greater than or equal to the value
+	/// - **value**: The value to round up
+	/// - **increment**: The increment that the value gets rounded to a multiple of
+	/// 
+	/// **Returns**: Returns the multiple of the increment that is not less than the value, returns the value if the increment is not greater than 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::ceil_to_multiple(1.2, 0.5);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::ceil_to_multiple(1.5, 0.5);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::ceil_to_multiple(1000.0, 256.0);
+	/// assert_eq!(1024.0, value);
+	/// let value = Math::ceil_to_multiple(-1.2, 0.5);
+	/// assert_eq!(-1.0, value);
+	/// let value = Math::ceil_to_multiple(1.2, 0.0);
+	/// assert_eq!(1.2, value);
+	/// ```
+	pub fn ceil_to_multiple(value: f32, increment: f32) -> f32 {
+		if increment <= 0.0 { return value; }
+		
+		Math::ceil(value / increment) * increment
+	}
+	
+	/// Raises the integer base by the integer exponent, computed purely with integer arithmetic
+	/// - **base**: The base number to power
+	/// - **exp**: The exponent to power with
+	/// 
+	/// **Returns**: Returns the powered number, returns `None` if the result overflows a `u32`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::checked_pow_u32(2, 10);
+	/// assert_eq!(Some(1024), value);
+	/// let value = Math::checked_pow_u32(3, 0);
+	/// assert_eq!(Some(1), value);
+	/// let value = Math::checked_pow_u32(2, 31);
+	/// assert_eq!(Some(2147483648), value);
+	/// let value = Math::checked_pow_u32(2, 32);
+	/// assert_eq!(None, value);
+	/// ```
+	pub fn checked_pow_u32(base: u32, exp: u32) -> Option<u32> {
+		let mut result = 1u32;
+		let mut base = base;
+		let mut exp = exp;
+		
+		while exp > 0 {
+			if exp & 1 == 1 { result = result.checked_mul(base)?; }
+			exp >>= 1;
+			if exp > 0 { base = base.checked_mul(base)?; }
+		}
+		
+		Some(result)
+	}
+	
 	/// Clamps the value between the min and max values
 	/// - **value**: The value to clamp with
 	/// - **min**: The lower-bound minimum value to clamp to
@@ -486,6 +632,56 @@ impl Math {
 	/// ```
 	pub fn clamp(value: f32, min: f32, max: f32) -> f32 { value.clamp(min, max) }
 	
+	/// Clamps the integer value between the min and max values
+	/// - **value**: The value to clamp with
+	/// - **min**: The lower-bound minimum value to clamp to
+	/// - **max**: The upper-bound maximum value to clamp to
+	/// 
+	/// **Returns**: Returns the clamped value
+	/// #### Remarks
+	/// Unlike `i32::clamp`, this does not panic when `min` is greater than `max`, it returns `max` instead
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::clamp_i32(20, 0, 10);
+	/// assert_eq!(10, value);
+	/// let value = Math::clamp_i32(-5, 0, 10);
+	/// assert_eq!(0, value);
+	/// let value = Math::clamp_i32(7, 0, 10);
+	/// assert_eq!(7, value);
+	/// let value = Math::clamp_i32(5, 10, 0);
+	/// assert_eq!(0, value);
+	/// ```
+	pub fn clamp_i32(value: i32, min: i32, max: i32) -> i32 { Math::min_i32(Math::max_i32(value, min), max) }
+	
+	/// Creates a value with the magnitude of the first value and the sign of the second value
+	/// - **magnitude**: The value to take the magnitude from
+	/// - **sign**: The value to take the sign from
+	/// 
+	/// **Returns**: Returns the magnitude with the sign of the given sign value
+	/// #### Remarks
+	/// Only the sign bit is looked at, so `-0.0` counts as negative, matching `sign`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::copysign(3.0, -1.0);
+	/// assert_eq!(-3.0, value);
+	/// let value = Math::copysign(-3.0, 0.0);
+	/// assert_eq!(3.0, value);
+	/// let value = Math::copysign(-3.0, 10.0);
+	/// assert_eq!(3.0, value);
+	/// let value = Math::copysign(3.0, -0.0);
+	/// assert_eq!(-3.0, value);
+	/// let value = Math::copysign(0.0, -2.0);
+	/// assert!(value == 0.0 && value.is_sign_negative());
+	/// ```
+	pub fn copysign(magnitude: f32, sign: f32) -> f32 {
+		#[cfg(not(feature = "no_std"))] { magnitude.copysign(sign) }
+		#[cfg(feature = "no_std")] {
+			f32::from_bits((magnitude.to_bits() & 0x7fff_ffff) | (sign.to_bits() & 0x8000_0000))
+		}
+	}
+	
 	/// Computes the cosine of the given angle in radians
 	/// - **angle**: The angle to compute cosine with in radians
 	/// 
@@ -670,6 +866,36 @@ impl Math {
 	/// ```
 	pub fn deg2rad(degrees: f32) -> f32 { Math::DEG_TO_RAD * degrees }
 	
+	/// Computes the Euclidean quotient of the value divided by the divisor
+	/// - **value**: The value to divide
+	/// - **divisor**: The value to divide by
+	/// 
+	/// **Returns**: Returns the whole number quotient such that the remainder from `rem_euclid` is never negative
+	/// #### Remarks
+	/// This is the same as `Math::floor(value / divisor)` for positive divisors,
+	/// and `-Math::floor(value / -divisor)` for negative divisors
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::div_euclid(-1.0, 3.0);
+	/// assert_eq!(-1.0, value);
+	/// let value = Math::div_euclid(7.0, 3.0);
+	/// assert_eq!(2.0, value);
+	/// let value = Math::div_euclid(7.0, -3.0);
+	/// assert_eq!(-2.0, value);
+	/// let value = Math::div_euclid(-7.0, -3.0);
+	/// assert_eq!(3.0, value);
+	/// let value = Math::div_euclid(-0.75, 0.5);
+	/// assert_eq!(-2.0, value);
+	/// ```
+	pub fn div_euclid(value: f32, divisor: f32) -> f32 {
+		#[cfg(not(feature = "no_std"))] { value.div_euclid(divisor) }
+		#[cfg(feature = "no_std")] {
+			if divisor < 0.0 { -Math::floor(value / -divisor) }
+			else { Math::floor(value / divisor) }
+		}
+	}
+	
 	/// Computes e^x
 	/// - **value**: The value to compute with
 	/// 
@@ -682,28 +908,48 @@ impl Math {
 	/// let value = Math::exp(-10.0);
 	/// assert_range!(0.000004539993, value);
 	/// let value = Math::exp(10.0);
-	/// assert_range!(22026.465, value);
+	/// assert!(Math::approx_relative(22026.465794806718, value));
 	/// let value = Math::exp(12.34);
 	/// assert_range!(228661.98, value, 0.05);
 	/// let value = Math::exp(2.9);
 	/// assert_range!(18.174147, value);
+	/// let value = Math::exp(1.0);
+	/// assert_range!(Math::E, value, 0.000001);
+	/// let value = Math::exp(20.0);
+	/// assert!(Math::approx_relative(485165195.4097903, value));
+	/// let value = Math::exp(-20.0);
+	/// assert!(Math::approx_relative(2.061153622438558e-9, value));
+	/// let value = Math::exp(50.0);
+	/// assert!(Math::approx_relative(5.184705528587072e21, value));
+	/// let value = Math::exp(88.0);
+	/// assert!(Math::approx_relative(1.6516362549940018e38, value));
+	/// let value = Math::exp(100.0);
+	/// assert_eq!(f32::INFINITY, value);
+	/// let value = Math::exp(-200.0);
+	/// assert_eq!(0.0, value);
 	/// ```
 	pub fn exp(value: f32) -> f32 {
 		#[cfg(not(feature = "no_std"))] { value.exp() }
 		#[cfg(feature = "no_std")] {
-			if value < 0.0 { return Math::exp(-value).recip(); }
+			const LN2_HI: f32 = 6.931_457_5e-1;
+			const LN2_LO: f32 = 1.428_606_8e-6;
 			
+			if value.is_nan() { return f32::NAN; }
+			if value > 88.72284 { return f32::INFINITY; }
+			if value < -103.97208 { return 0.0; }
+			
+			// Reduces the value to r + k * ln(2) so the series only has to handle |r| <= ln(2) / 2
+			let k = Math::floor(value / Math::LN2 + 0.5);
+			let r = (value - k * LN2_HI) - k * LN2_LO;
 			let mut result = 1.0;
 			let mut term = 1.0;
-			let mut n = 1;
 			
-			while n <= 100 {
-				term *= value / n as f32;
+			for n in 1..=10 {
+				term *= r / n as f32;
 				result += term;
-				n += 1;
 			}
 			
-			return result;
+			Math::scale_by_pow2(result, k as i32)
 		}
 	}
 	
@@ -732,6 +978,43 @@ impl Math {
 		}
 	}
 	
+	/// Computes e^x - 1, accurately even when x is close to zero
+	/// - **value**: The value to compute with
+	/// 
+	/// **Returns**: Returns the computed e^x - 1
+	/// #### Remarks
+	/// Computing `exp(x) - 1.0` loses most of it's precision for small values since `exp(x)` is very close to 1.0,
+	/// this avoids that by never computing the 1.0 in the first place
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::expm1(0.0);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::expm1(0.0001);
+	/// assert_range!(0.000100005, value, 0.000000001);
+	/// assert!(!Math::approx_epsilon(0.000100005, Math::exp(0.0001) - 1.0, 0.000000001));
+	/// let value = Math::expm1(1.0);
+	/// assert_range!(1.7182817, value);
+	/// let value = Math::expm1(-2.0);
+	/// assert_range!(-0.86466473, value);
+	/// ```
+	pub fn expm1(value: f32) -> f32 {
+		#[cfg(not(feature = "no_std"))] { value.exp_m1() }
+		#[cfg(feature = "no_std")] {
+			if Math::abs(value) >= 0.5 { return Math::exp(value) - 1.0; }
+			
+			let mut result = 0.0;
+			let mut term = 1.0;
+			
+			for n in 1..12 {
+				term *= value / n as f32;
+				result += term;
+			}
+			
+			result
+		}
+	}
+	
 	/// Gets the largest integer number that is less than or equal to the given number
 	/// - **value**: The value to get the floor with
 	/// 
@@ -761,6 +1044,53 @@ impl Math {
 		}
 	}
 	
+	/// Rounds the value down to the largest multiple of the increment that is less than or equal to the value
+	/// - **value**: The value to round down
+	/// - **increment**: The increment that the value gets rounded to a multiple of
+	/// 
+	/// **Returns**: Returns the multiple of the increment that is not greater than the value, returns the value if the increment is not greater than 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::floor_to_multiple(1.2, 0.5);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::floor_to_multiple(1.5, 0.5);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::floor_to_multiple(37.0, 10.0);
+	/// assert_eq!(30.0, value);
+	/// let value = Math::floor_to_multiple(-1.2, 0.5);
+	/// assert_eq!(-1.5, value);
+	/// let value = Math::floor_to_multiple(1.2, 0.0);
+	/// assert_eq!(1.2, value);
+	/// ```
+	pub fn floor_to_multiple(value: f32, increment: f32) -> f32 {
+		if increment <= 0.0 { return value; }
+		
+		Math::floor(value / increment) * increment
+	}
+	
+	/// Computes a fused multiply-add: `(a * b) + c`, rounding only once when supported
+	/// - **a**: The first number to multiply with
+	/// - **b**: The second number to multiply with
+	/// - **c**: The number to add to the product
+	/// 
+	/// **Returns**: Returns the product of the first two numbers added with the third number
+	/// #### Remarks
+	/// With the standard library this is a single operation that is more accurate than multiplying and adding,
+	/// useful for accumulating dot products or evaluating polynomials. With `no_std` it falls back to `a * b + c`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::fma(2.0, 3.0, 4.0);
+	/// assert_eq!(10.0, value);
+	/// let value = Math::fma(-1.5, 2.0, 0.25);
+	/// assert_range!(-2.75, value);
+	/// ```
+	pub fn fma(a: f32, b: f32, c: f32) -> f32 {
+		#[cfg(not(feature = "no_std"))] { a.mul_add(b, c) }
+		#[cfg(feature = "no_std")] { a * b + c }
+	}
+	
 	/// Gets the fractional part of the value, getting only a value between 0 and 1
 	/// - **value**: The value to get the fraction from
 	/// 
@@ -781,6 +1111,114 @@ impl Math {
 	/// ```
 	pub fn fract(value: f32) -> f32 { value - Math::floor(value) }
 	
+	/// Computes the gamma function, the extension of the factorial to real numbers where `gamma(n) = (n - 1)!`
+	/// - **value**: The value to compute the gamma function with
+	/// 
+	/// **Returns**: Returns the computed gamma function. Returns `infinity` if the value is 0.0
+	/// and `NaN` if the value is a negative integer, as those are the poles of the gamma function
+	/// #### Remarks
+	/// This uses the Lanczos approximation, except for positive whole numbers which are computed as exact factorials
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::gamma(1.0);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::gamma(5.0);
+	/// assert_eq!(24.0, value);
+	/// let value = Math::gamma(0.5);
+	/// assert_range!(1.7724539, value);
+	/// let value = Math::gamma(3.5);
+	/// assert_range!(3.3233509, value);
+	/// let value = Math::gamma(-2.5);
+	/// assert_range!(-0.9453087, value);
+	/// let value = Math::gamma(0.0);
+	/// assert!(value.is_infinite());
+	/// let value = Math::gamma(-2.0);
+	/// assert!(value.is_nan());
+	/// ```
+	pub fn gamma(value: f32) -> f32 {
+		if value.is_nan() { return f32::NAN; }
+		if value == 0.0 { return Math::copysign(f32::INFINITY, value); }
+		
+		let whole = Math::floor(value) == value;
+		
+		if whole && value < 0.0 { return f32::NAN; }
+		if whole && value <= 35.0 {
+			let mut result = 1.0;
+			
+			for n in 2..(value as i32) {
+				result *= n as f32;
+			}
+			
+			return result;
+		}
+		if value < 0.5 {
+			// Reflection formula: gamma(x) * gamma(1 - x) = pi / sin(pi * x)
+			return Math::PI / (Math::sin(Math::PI * value) * Math::gamma(1.0 - value));
+		}
+		
+		Math::exp(Math::lgamma(value))
+	}
+	
+	/// Gets the ratio of where the value sits between the two bounds, the inverse of `lerp`
+	/// - **a**: The lower bound, mapping to 0.0
+	/// - **b**: The upper bound, mapping to 1.0
+	/// - **value**: The value to get the ratio of
+	/// 
+	/// **Returns**: Returns the ratio clamped between 0.0 and 1.0, returns 0.0 if both bounds are the same
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::inverse_lerp(10.0, 20.0, 15.0);
+	/// assert_eq!(0.5, value);
+	/// let value = Math::inverse_lerp(10.0, 20.0, 25.0);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::inverse_lerp(20.0, 10.0, 12.0);
+	/// assert_eq!(0.8, value);
+	/// let value = Math::inverse_lerp(5.0, 5.0, 5.0);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn inverse_lerp(a: f32, b: f32, value: f32) -> f32 { Math::clamp(Math::inverse_lerp_unclamped(a, b, value), 0.0, 1.0) }
+	
+	/// Gets the ratio of where the value sits between the two bounds, the inverse of `lerp_unclamped`
+	/// - **a**: The bound mapping to 0.0
+	/// - **b**: The bound mapping to 1.0
+	/// - **value**: The value to get the ratio of
+	/// 
+	/// **Returns**: Returns the ratio (not clamped), returns 0.0 if both bounds are the same
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::inverse_lerp_unclamped(10.0, 20.0, 15.0);
+	/// assert_eq!(0.5, value);
+	/// let value = Math::inverse_lerp_unclamped(10.0, 20.0, 25.0);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::inverse_lerp_unclamped(10.0, 20.0, 0.0);
+	/// assert_eq!(-1.0, value);
+	/// let value = Math::inverse_lerp_unclamped(5.0, 5.0, 8.0);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn inverse_lerp_unclamped(a: f32, b: f32, value: f32) -> f32 {
+		if a == b { 0.0 }
+		else { (value - a) / (b - a) }
+	}
+	
+	/// Counts the number of zeros before the highest set bit within the given value
+	/// - **value**: The value to count the zeros with
+	/// 
+	/// **Returns**: Returns the number of leading zeros within the binary representation of the value
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::leading_zeros(1);
+	/// assert_eq!(31, value);
+	/// let value = Math::leading_zeros(1024);
+	/// assert_eq!(21, value);
+	/// let value = Math::leading_zeros(0);
+	/// assert_eq!(32, value);
+	/// ```
+	pub fn leading_zeros(value: u32) -> u32 { value.leading_zeros() }
+	
 	/// Linearly interpolates between the first and second values
 	/// - **a**: The first value to start from
 	/// - **b**: The second value to end from
@@ -801,6 +1239,60 @@ impl Math {
 	/// ```
 	pub fn lerp(a: f32, b: f32, t: f32) -> f32 { Math::lerp_unclamped(a, b, Math::clamp(t, 0.0, 1.0)) }
 	
+	/// Linearly interpolates between two angles in radians, taking the shortest way around the circle
+	/// - **a**: The angle to start from in radians
+	/// - **b**: The angle to end at in radians
+	/// - **t**: The ratio value to interpolate between both angles. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated angle in radians, wrapped between 0.0 and 2π
+	/// #### Remarks
+	/// The difference between the angles is wrapped between -π and π before interpolating,
+	/// so lerping across the 0 / 2π seam doesn't go the long way around
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::lerp_angle(0.0, Math::PI_OVER_2, 0.5);
+	/// assert_range!(Math::PI_OVER_4, value);
+	/// let value = Math::lerp_angle(Math::TWO_PI - 0.5, 0.5, 0.75);
+	/// assert_range!(0.25, value);
+	/// let value = Math::lerp_angle(0.5, Math::TWO_PI - 0.5, 0.75);
+	/// assert_range!(Math::TWO_PI - 0.25, value);
+	/// ```
+	pub fn lerp_angle(a: f32, b: f32, t: f32) -> f32 {
+		let delta = Math::wrap_angle(b - a);
+		
+		Math::rem_euclid(a + delta * Math::clamp(t, 0.0, 1.0), Math::TWO_PI)
+	}
+	
+	/// Linearly interpolates between two angles in degrees, taking the shortest way around the circle
+	/// - **a**: The angle to start from in degrees
+	/// - **b**: The angle to end at in degrees
+	/// - **t**: The ratio value to interpolate between both angles. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated angle in degrees, wrapped between 0.0 and 360.0
+	/// #### Remarks
+	/// The difference between the angles is wrapped between -180.0 and 180.0 before interpolating,
+	/// so lerping across the 0 / 360 seam doesn't go the long way around
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::lerp_angle_deg(350.0, 10.0, 0.5);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::lerp_angle_deg(350.0, 10.0, 0.75);
+	/// assert_eq!(5.0, value);
+	/// let value = Math::lerp_angle_deg(10.0, 350.0, 0.75);
+	/// assert_eq!(355.0, value);
+	/// let value = Math::lerp_angle_deg(30.0, 90.0, 0.5);
+	/// assert_eq!(60.0, value);
+	/// let value = Math::lerp_angle_deg(350.0, 10.0, 2.0);
+	/// assert_eq!(10.0, value);
+	/// ```
+	pub fn lerp_angle_deg(a: f32, b: f32, t: f32) -> f32 {
+		let delta = Math::wrap_angle_deg(b - a);
+		
+		Math::rem_euclid(a + delta * Math::clamp(t, 0.0, 1.0), 360.0)
+	}
+	
 	/// Linearly interpolates between the first and second values (not clamped)
 	/// - **a**: The first value to start from
 	/// - **b**: The second value to end from
@@ -821,6 +1313,52 @@ impl Math {
 	/// ```
 	pub fn lerp_unclamped(a: f32, b: f32, t: f32) -> f32 { a + t * (b - a) }
 	
+	/// Computes the natural log of the absolute value of the gamma function
+	/// - **value**: The value to compute the log-gamma function with
+	/// 
+	/// **Returns**: Returns the computed log-gamma function. Returns `infinity` if the value is 0.0 or a negative integer
+	/// #### Remarks
+	/// This uses the Lanczos approximation, and is useful when `gamma` itself would overflow
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::lgamma(1.0);
+	/// assert_range!(0.0, value);
+	/// let value = Math::lgamma(5.0);
+	/// assert_range!(3.1780539, value);
+	/// let value = Math::lgamma(0.5);
+	/// assert_range!(0.5723649, value);
+	/// let value = Math::lgamma(100.0);
+	/// assert_range!(359.13420, value, 0.001);
+	/// let value = Math::lgamma(4.5);
+	/// assert_range!(2.4537366, value);
+	/// let value = Math::lgamma(-2.5);
+	/// assert_range!(-0.0562437, value);
+	/// let value = Math::lgamma(-3.0);
+	/// assert!(value.is_infinite());
+	/// ```
+	pub fn lgamma(value: f32) -> f32 {
+		const COEFFICIENTS: [f32; 6] = [76.18009, -86.50532, 24.0141, -1.2317395, 0.001208651, -0.000005395239];
+		
+		if value.is_nan() { return f32::NAN; }
+		if value <= 0.0 && Math::floor(value) == value { return f32::INFINITY; }
+		if value < 0.5 {
+			// Reflection formula: gamma(x) * gamma(1 - x) = pi / sin(pi * x)
+			return Math::ln(Math::PI / Math::abs(Math::sin(Math::PI * value))) - Math::lgamma(1.0 - value);
+		}
+		
+		let mut x = value;
+		let mut series = 1.0;
+		let tmp = value + 5.5;
+		
+		for coefficient in COEFFICIENTS {
+			x += 1.0;
+			series += coefficient / x;
+		}
+		
+		(value + 0.5) * Math::ln(tmp) - tmp + Math::ln(2.5066283 * series / value)
+	}
+	
 	/// Computes the natural log of the given number
 	/// - **value**: The value to compute the natural log of
 	/// 
@@ -845,44 +1383,53 @@ impl Math {
 	/// assert!(value.is_nan());
 	/// let value = Math::ln(0.0);
 	/// assert!(value.is_infinite());
+	/// let value = Math::ln(0.5);
+	/// assert_range!(-0.6931471805599453, value, 0.000001);
+	/// let value = Math::ln(2.0);
+	/// assert_range!(0.6931471805599453, value, 0.000001);
+	/// let value = Math::ln(7.0);
+	/// assert_range!(1.9459101090932196, value, 0.000001);
+	/// let value = Math::ln(1000.0);
+	/// assert_range!(6.907755278982137, value, 0.000001);
 	/// ```
 	pub fn ln(value: f32) -> f32 {
 		#[cfg(not(feature = "no_std"))] { value.ln() }
 		#[cfg(feature = "no_std")] {
-			if value.is_nan() { return f32::NAN; }
+			if value.is_nan() || value < 0.0 { return f32::NAN; }
 			if value == 0.0 { return f32::NEG_INFINITY; }
-			if value < 0.0 { return f32::NAN; }
-			if value < 1.0 { return -Math::ln(value.recip()); }
 			if value.is_infinite() { return f32::INFINITY; }
-			if value == 1.0 { return 0.0; }
 			
 			let mut x = value;
-			let mut ln10_count = 0;
-			let mut ln2_count = 0;
+			let mut exponent = 0;
 			
-			while x > 10.0 {
-				x /= 10.0;
-				ln10_count += 1;
-			}
-			while x >= 2.0 {
-				x /= 2.0;
-				ln2_count += 1;
+			// Brings subnormal numbers into the normal range so the exponent can be read from the bits
+			if x < f32::MIN_POSITIVE {
+				x *= 33554432.0;
+				exponent -= 25;
 			}
 			
-			if x == 1.0 { return ln2_count as f32 * Math::LN2 + ln10_count as f32 * Math::LN10; }
+			// Splits the value into mantissa * 2^exponent, with the mantissa between sqrt(2) / 2 and sqrt(2)
+			let bits = x.to_bits();
+			let mut mantissa = f32::from_bits((bits & 0x007f_ffff) | 0x3f80_0000);
 			
-			let term = x - 1.0;
-			let mut power = term;
-			let mut series = power;
+			exponent += ((bits >> 23) & 0xff) as i32 - 127;
+			if mantissa > core::f32::consts::SQRT_2 {
+				mantissa /= 2.0;
+				exponent += 1;
+			}
 			
-			for i in 2..17 {
-				let negative = if i % 2 == 0 { -1.0 } else { 1.0 };
-				
-				power *= term;
-				series += negative * power / i as f32;
+			// ln(m) = 2 * atanh(s), where s = (m - 1) / (m + 1)
+			let s = (mantissa - 1.0) / (mantissa + 1.0);
+			let s2 = s * s;
+			let mut power = s;
+			let mut series = s;
+			
+			for i in 1..8 {
+				power *= s2;
+				series += power / (2 * i + 1) as f32;
 			}
 			
-			return ln2_count as f32 * Math::LN2 + ln10_count as f32 * Math::LN10 + series;
+			exponent as f32 * Math::LN2 + 2.0 * series
 		}
 	}
 	
@@ -982,6 +1529,27 @@ impl Math {
 		#[cfg(feature = "no_std")] { Math::ln(value) * Math::LN2.recip() }
 	}
 	
+	/// Gets the index of the highest set bit of the given value, which is the rounded down log in base 2
+	/// - **value**: The value to compute the log with
+	/// 
+	/// **Returns**: Returns the rounded down log in base 2 of the value, returns 0 if the value is 0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::log2_floor(1024);
+	/// assert_eq!(10, value);
+	/// let value = Math::log2_floor(1023);
+	/// assert_eq!(9, value);
+	/// let value = Math::log2_floor(1);
+	/// assert_eq!(0, value);
+	/// let value = Math::log2_floor(0);
+	/// assert_eq!(0, value);
+	/// ```
+	pub fn log2_floor(value: u32) -> u32 {
+		if value == 0 { 0 }
+		else { u32::BITS - 1 - value.leading_zeros() }
+	}
+	
 	/// Maps the value from one range into another range
 	/// - **value**: The value to map
 	/// - **in_range**: The starting input range to map from
@@ -1025,6 +1593,60 @@ impl Math {
 	/// ```
 	pub fn max(a: f32, b: f32) -> f32 { a.max(b) }
 	
+	/// Gets the maximum value between the two integer values
+	/// - **a**: The first value to get the maximum value from
+	/// - **b**: The second value to get the maximum value from
+	/// 
+	/// **Returns**: Returns the maximum number between the two values
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::max_i32(-1, 1);
+	/// assert_eq!(1, value);
+	/// let value = Math::max_i32(-19, -20);
+	/// assert_eq!(-19, value);
+	/// ```
+	pub fn max_i32(a: i32, b: i32) -> i32 { if a > b { a } else { b } }
+	
+	/// Gets the mean (average) of the values
+	/// - **values**: The values to get the mean of
+	/// 
+	/// **Returns**: Returns the mean of the values, returns 0.0 if there are no values
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::mean(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+	/// assert_eq!(5.0, value);
+	/// let value = Math::mean(&[3.5]);
+	/// assert_eq!(3.5, value);
+	/// let value = Math::mean(&[]);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn mean(values: &[f32]) -> f32 {
+		if values.is_empty() { 0.0 }
+		else { values.iter().sum::<f32>() / values.len() as f32 }
+	}
+	
+	/// Gets the median (middle value) of the values, sorting the values in place
+	/// - **values**: The values to get the median of, which end up sorted
+	/// 
+	/// **Returns**: Returns the median of the values, returns 0.0 if there are no values
+	/// #### Remarks
+	/// With an even number of values, the median is the mean of the two middle values.
+	/// This is the same as `Math::percentile(values, 50.0)`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let mut values = [7.0, 1.0, 5.0, 3.0, 9.0];
+	/// assert_eq!(5.0, Math::median(&mut values));
+	/// assert_eq!([1.0, 3.0, 5.0, 7.0, 9.0], values);
+	/// let value = Math::median(&mut [4.0, 1.0, 3.0, 2.0]);
+	/// assert_eq!(2.5, value);
+	/// let value = Math::median(&mut []);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn median(values: &mut [f32]) -> f32 { Math::percentile(values, 50.0) }
+	
 	/// Gets the minimum value between the two values
 	/// - **a**: The first value to get the minimum value from
 	/// - **b**: The second value to get the minimum value from
@@ -1040,6 +1662,21 @@ impl Math {
 	/// ```
 	pub fn min(a: f32, b: f32) -> f32 { a.min(b) }
 	
+	/// Gets the minimum value between the two integer values
+	/// - **a**: The first value to get the minimum value from
+	/// - **b**: The second value to get the minimum value from
+	/// 
+	/// **Returns**: Returns the minimum number between the two values
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::min_i32(-1, 1);
+	/// assert_eq!(-1, value);
+	/// let value = Math::min_i32(-19, -20);
+	/// assert_eq!(-20, value);
+	/// ```
+	pub fn min_i32(a: i32, b: i32) -> i32 { if a < b { a } else { b } }
+	
 	/// Gets the minimum and maximum value returned as a tuple correctly sorted
 	/// - **a**: The first value to get the minimum and maximum value from
 	/// - **b**: The second value to get the minimum and maximum value from
@@ -1055,6 +1692,115 @@ impl Math {
 	/// ```
 	pub fn min_max(a: f32, b: f32) -> (f32, f32) { (Math::min(a, b), Math::max(a, b)) }
 	
+	/// Splits the value into it's integer and fractional parts, where both parts keep the sign of the value
+	/// - **value**: The value to split
+	/// 
+	/// **Returns**: Returns a tuple that holds the integer and fractional parts respectively
+	/// #### Remarks
+	/// The integer part is the same as `trunc`, unlike `fract` which always returns a positive fraction
+	/// measured from `floor`. So `Math::fract(-4.9)` is `0.1` while the fractional part of `Math::modf(-4.9)` is `-0.9`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range_tuple2};
+	/// let value = Math::modf(4.9);
+	/// assert_range_tuple2!((4.0, 0.9), value);
+	/// let value = Math::modf(-4.9);
+	/// assert_range_tuple2!((-4.0, -0.9), value);
+	/// let value = Math::modf(3.0);
+	/// assert_eq!((3.0, 0.0), value);
+	/// ```
+	pub fn modf(value: f32) -> (f32, f32) {
+		let integer = Math::trunc(value);
+		
+		(integer, value - integer)
+	}
+	
+	/// Replaces NaN and infinite values with the given replacements, leaving finite values unchanged
+	/// - **value**: The value to sanitize
+	/// - **nan_replacement**: The value to use if the value is NaN
+	/// - **inf_replacement**: The value to use if the value is infinite, where negative infinity uses the negated replacement
+	/// 
+	/// **Returns**: Returns the sanitized value
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::nan_to_num(f32::NAN, 0.0, 1000.0);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::nan_to_num(f32::INFINITY, 0.0, 1000.0);
+	/// assert_eq!(1000.0, value);
+	/// let value = Math::nan_to_num(f32::NEG_INFINITY, 0.0, 1000.0);
+	/// assert_eq!(-1000.0, value);
+	/// let value = Math::nan_to_num(-12.5, 0.0, 1000.0);
+	/// assert_eq!(-12.5, value);
+	/// ```
+	pub fn nan_to_num(value: f32, nan_replacement: f32, inf_replacement: f32) -> f32 {
+		if value.is_nan() { nan_replacement }
+		else if value == f32::INFINITY { inf_replacement }
+		else if value == f32::NEG_INFINITY { -inf_replacement }
+		else { value }
+	}
+	
+	/// Gets the percentile of the values, linearly interpolating between the two closest ranks and sorting the values in place
+	/// - **values**: The values to get the percentile of, which end up sorted
+	/// - **percent**: The percentile to get, clamped between 0.0 and 100.0
+	/// 
+	/// **Returns**: Returns the percentile of the values, returns 0.0 if there are no values
+	/// #### Remarks
+	/// The 0th percentile is the smallest value and the 100th percentile is the largest value.
+	/// NaN values are sorted after every other value
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let mut values = [15.0, 20.0, 35.0, 40.0, 50.0];
+	/// assert_range!(46.0, Math::percentile(&mut values, 90.0));
+	/// assert_eq!(35.0, Math::percentile(&mut values, 50.0));
+	/// assert_eq!(15.0, Math::percentile(&mut values, 0.0));
+	/// assert_eq!(50.0, Math::percentile(&mut values, 100.0));
+	/// assert_eq!(50.0, Math::percentile(&mut values, 150.0));
+	/// let value = Math::percentile(&mut [3.0, 1.0, 2.0, 4.0], 25.0);
+	/// assert_eq!(1.75, value);
+	/// let value = Math::percentile(&mut [], 90.0);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn percentile(values: &mut [f32], percent: f32) -> f32 {
+		if values.is_empty() { return 0.0; }
+		
+		values.sort_unstable_by(f32::total_cmp);
+		
+		let rank = Math::clamp(percent, 0.0, 100.0) / 100.0 * (values.len() - 1) as f32;
+		let lower = Math::floor(rank) as usize;
+		let upper = Math::min_i32(lower as i32 + 1, values.len() as i32 - 1) as usize;
+		
+		Math::lerp_unclamped(values[lower], values[upper], rank - lower as f32)
+	}
+	
+	/// Bounces the value back and forth between 0 and the length, unlike `repeat` which wraps back to the start
+	/// - **value**: The value to bounce
+	/// - **length**: The length to bounce within, where the value will turn around
+	/// 
+	/// **Returns**: Returns the value bounced within 0 and the length. Returns 0 if the length is not positive
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::pingpong(1.5, 3.0);
+	/// assert_range!(1.5, value);
+	/// let value = Math::pingpong(5.3, 3.0);
+	/// assert_range!(0.7, value);
+	/// let value = Math::pingpong(7.0, 3.0);
+	/// assert_range!(1.0, value);
+	/// let value = Math::pingpong(-1.0, 3.0);
+	/// assert_range!(1.0, value);
+	/// let value = Math::pingpong(-4.5, 3.0);
+	/// assert_range!(1.5, value);
+	/// ```
+	pub fn pingpong(value: f32, length: f32) -> f32 {
+		if length <= 0.0 { return 0.0; }
+		
+		let t = Math::rem_euclid(value, 2.0 * length);
+		
+		length - Math::abs(t - length)
+	}
+	
 	/// Raised the value by the power (as a floating point number)
 	/// - **value**: The value to raise with
 	/// - **power**: The power to raise by
@@ -1095,6 +1841,10 @@ impl Math {
 	/// - **b**: The number to power with
 	/// 
 	/// **Returns**: Returns the powered number
+	/// #### Remarks
+	/// Any number to the power of 0 is 1 (including 0), while 0 to a negative power is infinity,
+	/// keeping the sign of the zero for odd powers the same as `f32::powi`.
+	/// Under `no_std` this uses exponentiation by squaring, so large exponents only take a few multiplications
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Math,assert_range};
@@ -1110,16 +1860,48 @@ impl Math {
 	/// assert_range!(0.0, value);
 	/// let value = Math::pow_i32(2.0, -3);
 	/// assert_range!(0.125, value);
+	/// let value = Math::pow_i32(5.0, 1);
+	/// assert_eq!(5.0, value);
+	/// let value = Math::pow_i32(5.0, -1);
+	/// assert_range!(0.2, value);
+	/// let value = Math::pow_i32(0.0, 1);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::pow_i32(0.0, -1);
+	/// assert_eq!(f32::INFINITY, value);
+	/// let value = Math::pow_i32(-0.0, -1);
+	/// assert_eq!(f32::NEG_INFINITY, value);
+	/// let value = Math::pow_i32(0.0, -2);
+	/// assert_eq!(f32::INFINITY, value);
+	/// let value = Math::pow_i32(1.0000001, 1000);
+	/// assert_range!(1.0001192, value, 0.000001);
+	/// let value = Math::pow_i32(-2.0, 31);
+	/// assert_eq!(-2147483648.0, value);
+	/// for exponent in -5i32..=5 {
+	///     let mut expected = 1.0;
+	///     for _ in 0..exponent.abs() { expected *= 1.5; }
+	///     if exponent < 0 { expected = 1.0 / expected; }
+	///     assert_range!(expected, Math::pow_i32(1.5, exponent), 0.000001);
+	/// }
 	/// ```
 	pub fn pow_i32(a: f32, b: i32) -> f32 {
 		#[cfg(not(feature = "no_std"))] { a.powi(b) }
 		#[cfg(feature = "no_std")] {
-			if b == 0 { return 1.0 }
+			if b == 0 { return 1.0; }
+			if b == 1 { return a; }
+			if b == -1 { return a.recip(); }
+			if a == 0.0 && b < 0 {
+				return if b % 2 == 0 { f32::INFINITY } else { Math::copysign(f32::INFINITY, a) };
+			}
 			
-			let mut result = a;
+			// Exponentiation by squaring, multiplying in the base's square for every set bit of the exponent
+			let mut base = a;
+			let mut exponent = b.unsigned_abs();
+			let mut result = 1.0;
 			
-			for _ in 1..Math::abs_i32(b) {
-				result *= a;
+			while exponent > 0 {
+				if exponent & 1 == 1 { result *= base; }
+				base *= base;
+				exponent >>= 1;
 			}
 			
 			if b < 0 { result.recip() }
@@ -1127,6 +1909,40 @@ impl Math {
 		}
 	}
 	
+	/// Raises the integer base by the integer exponent, computed purely with integer arithmetic
+	/// - **base**: The base number to power
+	/// - **exp**: The exponent to power with
+	/// 
+	/// **Returns**: Returns the powered number, wrapping around on overflow
+	/// #### Remarks
+	/// This uses exponentiation by squaring, so it's exact unlike going through `pow_i32` with floating point numbers.
+	/// Use `checked_pow_u32` if overflowing should be detected instead of wrapped around
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::pow_u32(2, 10);
+	/// assert_eq!(1024, value);
+	/// let value = Math::pow_u32(3, 0);
+	/// assert_eq!(1, value);
+	/// let value = Math::pow_u32(7, 11);
+	/// assert_eq!(1977326743, value);
+	/// let value = Math::pow_u32(2, 32);
+	/// assert_eq!(0, value);
+	/// ```
+	pub fn pow_u32(base: u32, exp: u32) -> u32 {
+		let mut result = 1u32;
+		let mut base = base;
+		let mut exp = exp;
+		
+		while exp > 0 {
+			if exp & 1 == 1 { result = result.wrapping_mul(base); }
+			base = base.wrapping_mul(base);
+			exp >>= 1;
+		}
+		
+		result
+	}
+	
 	/// Converts the value from radians to degrees
 	/// - **radians**: The value in radians to convert
 	/// 
@@ -1141,6 +1957,61 @@ impl Math {
 	/// ```
 	pub fn rad2deg(radians: f32) -> f32 { Math::RAD_TO_DEG * radians }
 	
+	/// Computes the Euclidean remainder of the value divided by the divisor
+	/// - **value**: The value to divide
+	/// - **divisor**: The value to divide by
+	/// 
+	/// **Returns**: Returns the remainder, which is always between 0.0 and the absolute value of the divisor
+	/// #### Remarks
+	/// Unlike the `%` operator the remainder is never negative, making it useful for wrapping indices around.
+	/// This is always equal to `value - divisor * Math::div_euclid(value, divisor)`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::rem_euclid(-1.0, 3.0);
+	/// assert_range!(2.0, value);
+	/// let value = Math::rem_euclid(7.0, 3.0);
+	/// assert_range!(1.0, value);
+	/// let value = Math::rem_euclid(7.0, -3.0);
+	/// assert_range!(1.0, value);
+	/// let value = Math::rem_euclid(-7.0, -3.0);
+	/// assert_range!(2.0, value);
+	/// let value = Math::rem_euclid(-0.75, 0.5);
+	/// assert_range!(0.25, value);
+	/// let value = Math::rem_euclid(5.5, 2.0);
+	/// assert_range!(1.5, value);
+	/// ```
+	pub fn rem_euclid(value: f32, divisor: f32) -> f32 {
+		#[cfg(not(feature = "no_std"))] { value.rem_euclid(divisor) }
+		#[cfg(feature = "no_std")] { value - divisor * Math::div_euclid(value, divisor) }
+	}
+	
+	/// Maps the value from one range into another range, clamping the result to stay within the output range
+	/// - **value**: The value to map
+	/// - **in_range**: The starting input range to map from
+	/// - **out_range**: The ending output range to map to, which can be reversed
+	/// 
+	/// **Returns**: Returns the mapped value clamped within the output range
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::remap_clamped(5.0, 0.0..10.0, 0.0..1.0);
+	/// assert_eq!(0.5, value);
+	/// let value = Math::map(15.0, 0.0..10.0, 0.0..1.0);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::remap_clamped(15.0, 0.0..10.0, 0.0..1.0);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::remap_clamped(-5.0, 0.0..10.0, 0.0..1.0);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::remap_clamped(15.0, 0.0..10.0, 100.0..50.0);
+	/// assert_eq!(50.0, value);
+	/// ```
+	pub fn remap_clamped(value: f32, in_range: Range<f32>, out_range: Range<f32>) -> f32 {
+		let (min, max) = Math::min_max(out_range.start, out_range.end);
+		
+		Math::clamp(Math::map(value, in_range, out_range), min, max)
+	}
+	
 	/// Repeats the value around the range, making sure it stays within the range
 	/// - **value**: The value to repeat
 	/// - **range**: The range to repeat around
@@ -1254,6 +2125,35 @@ impl Math {
 		return truncated / pow10;
 	}
 	
+	/// Rounds the value to the nearest multiple of the increment
+	/// - **value**: The value to round
+	/// - **increment**: The increment that the value gets rounded to a multiple of
+	/// 
+	/// **Returns**: Returns the nearest multiple of the increment, returns the value if the increment is not greater than 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::round_to_multiple(1.3, 0.5);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::round_to_multiple(1.2, 0.5);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::round_to_multiple(-1.3, 0.5);
+	/// assert_eq!(-1.5, value);
+	/// let value = Math::round_to_multiple(37.0, 10.0);
+	/// assert_eq!(40.0, value);
+	/// let value = Math::round_to_multiple(0.7, 0.1);
+	/// assert_range!(0.7, value);
+	/// let value = Math::round_to_multiple(1.3, 0.0);
+	/// assert_eq!(1.3, value);
+	/// let value = Math::round_to_multiple(1.3, -0.5);
+	/// assert_eq!(1.3, value);
+	/// ```
+	pub fn round_to_multiple(value: f32, increment: f32) -> f32 {
+		if increment <= 0.0 { return value; }
+		
+		Math::round(value / increment) * increment
+	}
+	
 	/// Computes the secant of the given angle in radians
 	/// - **angle**: The given angle to compute the secant with in radians
 	/// 
@@ -1320,6 +2220,47 @@ impl Math {
 		}
 	}
 	
+	/// Gets the sign of the given integer value
+	/// - **value**: The value to check the sign with
+	/// 
+	/// **Returns**: Returns 1 if the value is positive, -1 if the value is negative, and 0 if the value is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::sign_i32(10);
+	/// assert_eq!(1, value);
+	/// let value = Math::sign_i32(-10);
+	/// assert_eq!(-1, value);
+	/// let value = Math::sign_i32(0);
+	/// assert_eq!(0, value);
+	/// ```
+	pub fn sign_i32(value: i32) -> i32 { value.signum() }
+	
+	/// Gets the sign of the given value, treating zero as having no sign
+	/// - **value**: The value to check the sign with
+	/// 
+	/// **Returns**: Returns 1.0 if the value is positive, -1.0 if the value is negative, and 0.0 if the value is zero
+	/// #### Remarks
+	/// Unlike `sign`, which always returns either 1.0 or -1.0 depending on the sign bit (even for `0.0` and `-0.0`),
+	/// this returns 0.0 for both `0.0` and `-0.0`. Returns `NaN` if the value is `NaN`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::signum_zero(10.0);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::signum_zero(-10.0);
+	/// assert_eq!(-1.0, value);
+	/// let value = Math::signum_zero(0.0);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::signum_zero(-0.0);
+	/// assert_eq!(0.0, value);
+	/// assert_eq!(-1.0, Math::sign(-0.0));
+	/// ```
+	pub fn signum_zero(value: f32) -> f32 {
+		if value == 0.0 { 0.0 }
+		else { Math::sign(value) }
+	}
+	
 	/// Computes the sine of the given angle in radians
 	/// - **angle**: The angle to compute sine with in radians
 	/// 
@@ -1462,6 +2403,34 @@ impl Math {
 	/// ```
 	pub fn sin_cos_deg(angle: f32) -> (f32, f32) { Math::sin_cos(Math::DEG_TO_RAD * angle) }
 	
+	/// Computes the sine, cosine and tangent of the angle in radians all at once
+	/// - **angle**: The angle to compute the sine, cosine and tangent with in radians
+	/// 
+	/// **Returns**: Returns a tuple of the sine, cosine and tangent respectively
+	/// #### Remarks
+	/// The sine and cosine are only computed once, with the tangent derived as `sin / cos`.
+	/// This means the tangent grows towards infinity as the angle approaches `PI_OVER_2` (and every half turn from it),
+	/// becoming infinite if the cosine is exactly zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let (sin, cos, tan) = Math::sin_cos_tan(Math::PI_OVER_4);
+	/// assert_range!(0.707106781, sin);
+	/// assert_range!(0.707106781, cos);
+	/// assert_range!(1.0, tan);
+	/// let (sin, cos, tan) = Math::sin_cos_tan(-1.0);
+	/// assert_range!(-0.841470985, sin);
+	/// assert_range!(0.540302306, cos);
+	/// assert_range!(-1.557407725, tan);
+	/// let (_, _, tan) = Math::sin_cos_tan(Math::PI_OVER_2 - 0.001);
+	/// assert!(tan > 999.0);
+	/// ```
+	pub fn sin_cos_tan(angle: f32) -> (f32, f32, f32) {
+		let (sin, cos) = Math::sin_cos(angle);
+		
+		(sin, cos, sin / cos)
+	}
+	
 	/// Computes the hyperbolic sine function
 	/// - **value**: The value to compute the hyperbolic sine function with
 	/// 
@@ -1494,6 +2463,36 @@ impl Math {
 		}
 	}
 	
+	/// Computes a smoother interpolation using Perlin's quintic polynomial that returns a number between 0.0 and 1.0
+	/// - **value**: The value for the interpolation, where `left_edge` &lt; `value` &lt; `right_edge`
+	/// - **left_edge**: The leftmost edge to where 0.0 would start at
+	/// - **right_edge**: The rightmost edge where 1.0 would start at
+	/// 
+	/// **Returns**: Returns a smoother interpolation that returns a number between 0.0 and 1.0
+	/// #### Remarks
+	/// Unlike `smoothstep`, both the first and second derivatives are zero at the edges
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::smootherstep(0.0, 0.0, 1.5);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::smootherstep(1.5, 0.0, 1.5);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::smootherstep(0.75, 0.0, 1.5);
+	/// assert_eq!(0.5, value);
+	/// let value = Math::smootherstep(-1.0, 0.0, 1.5);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::smootherstep(2.0, 0.0, 1.5);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::smootherstep(0.5, -1.0, 3.0);
+	/// assert_range!(0.27520752, value);
+	/// ```
+	pub fn smootherstep(value: f32, left_edge: f32, right_edge: f32) -> f32 {
+		let y = Math::clamp((value - left_edge) / (right_edge - left_edge), 0.0, 1.0);
+		
+		y * y * y * (y * (6.0 * y - 15.0) + 10.0)
+	}
+	
 	/// Computes a smooth Hermite interpolation that returns a number between 0.0 and 1.0
 	/// - **value**: The value for the interpolation, where `left_edge` &lt; `value` &lt; `right_edge`
 	/// - **left_edge**: The leftmost edge to where 0.0 would start at
@@ -1518,6 +2517,60 @@ impl Math {
 		return y * y * (3.0 - 2.0 * y);
 	}
 	
+	/// Snaps the value to the nearest multiple of the increment, useful for snapping onto a grid.
+	/// This is the same as `Math::round_to_multiple`
+	/// - **value**: The value to snap
+	/// - **increment**: The size of each step of the grid
+	/// 
+	/// **Returns**: Returns the snapped value, returns the value if the increment is not greater than 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::snap(1.3, 0.5);
+	/// assert_eq!(1.5, value);
+	/// let value = Math::snap(-1.3, 0.5);
+	/// assert_eq!(-1.5, value);
+	/// let value = Math::snap(7.9, 2.0);
+	/// assert_eq!(8.0, value);
+	/// let value = Math::snap(1.3, 0.0);
+	/// assert_eq!(1.3, value);
+	/// ```
+	pub fn snap(value: f32, increment: f32) -> f32 { Math::round_to_multiple(value, increment) }
+	
+	/// Solves the system of two linear equations `a11 * x + a12 * y = b1` and `a21 * x + a22 * y = b2` using Cramer's rule
+	/// - **a11**: The coefficient of `x` in the first equation
+	/// - **a12**: The coefficient of `y` in the first equation
+	/// - **a21**: The coefficient of `x` in the second equation
+	/// - **a22**: The coefficient of `y` in the second equation
+	/// - **b1**: The constant of the first equation
+	/// - **b2**: The constant of the second equation
+	/// 
+	/// **Returns**: Returns the solution as a tuple of `(x, y)`, returns `None` if the determinant is approximately zero
+	/// #### Remarks
+	/// The determinant is compared against the size of the largest coefficient,
+	/// so that systems with small coefficients can still be solved
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::solve_linear_system_2x2(2.0, 1.0, 1.0, -1.0, 5.0, 1.0);
+	/// assert_eq!(Some((2.0, 1.0)), value);
+	/// let value = Math::solve_linear_system_2x2(3.0, -2.0, 1.0, 4.0, 0.5, 6.0);
+	/// assert_eq!(Some((1.0, 1.25)), value);
+	/// let value = Math::solve_linear_system_2x2(1.0, 2.0, 2.0, 4.0, 3.0, 6.0);
+	/// assert_eq!(None, value);
+	/// let (x, y) = Math::solve_linear_system_2x2(0.0002, 0.0001, 0.0001, -0.0001, 0.0005, 0.0001).unwrap();
+	/// assert_range!(2.0, x);
+	/// assert_range!(1.0, y);
+	/// ```
+	pub fn solve_linear_system_2x2(a11: f32, a12: f32, a21: f32, a22: f32, b1: f32, b2: f32) -> Option<(f32, f32)> {
+		let determinant = a11 * a22 - a12 * a21;
+		let scale = Math::max(Math::max(Math::abs(a11), Math::abs(a12)), Math::max(Math::abs(a21), Math::abs(a22)));
+		
+		if Math::abs(determinant) <= 0.000001 * scale * scale { return None; }
+		
+		Some(((b1 * a22 - a12 * b2) / determinant, (a11 * b2 - b1 * a21) / determinant))
+	}
+	
 	/// Gets the square root of the given number
 	/// - **value**: The number to square root
 	/// 
@@ -1555,6 +2608,39 @@ impl Math {
 		}
 	}
 	
+	/// Gets the sample standard deviation of the values
+	/// - **values**: The values to get the standard deviation of
+	/// 
+	/// **Returns**: Returns the square root of the sample variance, returns 0.0 if there are less than two values
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::std_dev(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+	/// assert_range!(2.13808993, value);
+	/// let value = Math::std_dev(&[3.5]);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn std_dev(values: &[f32]) -> f32 { Math::sqrt(Math::variance(values)) }
+	
+	/// Compares the value with the edge, much like the `step` function found in shaders
+	/// - **edge**: The edge where the step happens
+	/// - **value**: The value to compare with the edge
+	/// 
+	/// **Returns**: Returns 0.0 if the value is below the edge, returns 1.0 otherwise
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::step(1.0, 0.5);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::step(1.0, 1.0);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::step(1.0, 3.5);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::step(-2.0, -3.0);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn step(edge: f32, value: f32) -> f32 { if value < edge { 0.0 } else { 1.0 } }
+	
 	/// Gets the tangent  of the angle in radians
 	/// - **angle**: The angle to compute the tangent with in radians
 	/// 
@@ -1661,6 +2747,81 @@ impl Math {
 			(value as i32) as f32
 		}
 	}
+	
+	/// Gets the sample variance of the values, dividing by one less than the number of values
+	/// - **values**: The values to get the variance of
+	/// 
+	/// **Returns**: Returns the sample variance of the values, returns 0.0 if there are less than two values
+	/// #### Remarks
+	/// A single value has no spread to measure, so 0.0 is returned instead of the NaN that dividing by zero would give
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::variance(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+	/// assert_range!(4.5714286, value);
+	/// let value = Math::variance(&[1.0, 1.0, 1.0]);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::variance(&[3.5]);
+	/// assert_eq!(0.0, value);
+	/// let value = Math::variance(&[]);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn variance(values: &[f32]) -> f32 {
+		if values.len() < 2 { return 0.0; }
+		
+		let mean = Math::mean(values);
+		let squared_differences: f32 = values.iter().map(|value| (value - mean) * (value - mean)).sum();
+		
+		squared_differences / (values.len() - 1) as f32
+	}
+	
+	/// Wraps the angle in radians to be within -π and π
+	/// - **radians**: The angle to wrap in radians
+	/// 
+	/// **Returns**: Returns the equivalent angle in radians between -π and π (inclusive)
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,assert_range};
+	/// let value = Math::wrap_angle(3.0 * Math::PI);
+	/// assert_range!(Math::PI, value, 0.001);
+	/// let value = Math::wrap_angle(Math::PI + Math::PI_OVER_2);
+	/// assert_range!(-Math::PI_OVER_2, value);
+	/// let value = Math::wrap_angle(-Math::TWO_PI - 1.0);
+	/// assert_range!(-1.0, value);
+	/// let value = Math::wrap_angle(1.0);
+	/// assert_eq!(1.0, value);
+	/// let value = Math::wrap_angle(-Math::PI);
+	/// assert_eq!(-Math::PI, value);
+	/// ```
+	pub fn wrap_angle(radians: f32) -> f32 {
+		if (-Math::PI..=Math::PI).contains(&radians) { return radians; }
+		
+		Math::PI - Math::rem_euclid(Math::PI - radians, Math::TWO_PI)
+	}
+	
+	/// Wraps the angle in degrees to be within -180 and 180
+	/// - **degrees**: The angle to wrap in degrees
+	/// 
+	/// **Returns**: Returns the equivalent angle in degrees between -180 and 180 (inclusive)
+	/// #### Examples
+	/// ```
+	/// # use mathx::Math;
+	/// let value = Math::wrap_angle_deg(540.0);
+	/// assert_eq!(180.0, value);
+	/// let value = Math::wrap_angle_deg(270.0);
+	/// assert_eq!(-90.0, value);
+	/// let value = Math::wrap_angle_deg(-370.0);
+	/// assert_eq!(-10.0, value);
+	/// let value = Math::wrap_angle_deg(45.0);
+	/// assert_eq!(45.0, value);
+	/// let value = Math::wrap_angle_deg(-180.0);
+	/// assert_eq!(-180.0, value);
+	/// ```
+	pub fn wrap_angle_deg(degrees: f32) -> f32 {
+		if (-180.0..=180.0).contains(&degrees) { return degrees; }
+		
+		180.0 - Math::rem_euclid(180.0 - degrees, 360.0)
+	}
 }
 
 // Private Functions
@@ -1710,6 +2871,28 @@ impl Math {
 	/// **Returns**: Returns the negated tuple
 	#[cfg(feature = "no_std")]
 	pub(self) fn negate_tuple(tuple: (f32, f32)) -> (f32, f32) { (-tuple.0, -tuple.1) }
+	
+	/// Multiplies the value by 2 to the power of the exponent by building the power of two from it's bits
+	/// - **value**: The value to scale
+	/// - **exponent**: The exponent of the power of two to scale with
+	/// 
+	/// **Returns**: Returns the scaled value
+	#[cfg(feature = "no_std")]
+	pub(self) fn scale_by_pow2(value: f32, exponent: i32) -> f32 {
+		let mut value = value;
+		let mut exponent = exponent;
+		
+		while exponent > 127 {
+			value *= f32::from_bits(254 << 23);
+			exponent -= 127;
+		}
+		while exponent < -126 {
+			value *= f32::from_bits(1 << 23);
+			exponent += 126;
+		}
+		
+		value * f32::from_bits(((exponent + 127) as u32) << 23)
+	}
 }
 
 #[doc(hidden)]
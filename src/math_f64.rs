@@ -0,0 +1,486 @@
+
+/// A "static" structure used to compute math functions with `f64` precision. It mirrors [`Math`](crate::Math)
+/// for when `f32` is not precise enough, such as with long running simulations. Just like `Math`,
+/// it uses the `f64` functions from the standard library and switches to an approximation when using `no_std`.
+pub struct MathF64;
+
+// Constants
+impl MathF64 {
+	pub const PI: f64 = core::f64::consts::PI;
+	pub const PI_OVER_2: f64 = core::f64::consts::FRAC_PI_2;
+	pub const PI_OVER_4: f64 = core::f64::consts::FRAC_PI_4;
+	pub const TWO_PI: f64 = core::f64::consts::TAU;
+	pub const E: f64 = core::f64::consts::E;
+	pub const DEG_TO_RAD: f64 = core::f64::consts::PI / 180.0;
+	pub const RAD_TO_DEG: f64 = 180.0 / core::f64::consts::PI;
+	pub const LN2: f64 = core::f64::consts::LN_2;
+	pub const LN10: f64 = core::f64::consts::LN_10;
+}
+
+// Public Functions
+impl MathF64 {
+	/// Gets the absolute value of the number
+	/// - **value**: The number to get the absolute value from
+	/// 
+	/// **Returns**: Returns the absolute value of the number
+	/// #### Examples
+	/// ```
+	/// # use mathx::MathF64;
+	/// let value = MathF64::abs(10.0);
+	/// assert_eq!(10.0, value);
+	/// let value = MathF64::abs(-10.0);
+	/// assert_eq!(10.0, value);
+	/// let value = MathF64::abs(-0.0);
+	/// assert_eq!(0.0, value);
+	/// assert!(value.is_sign_positive());
+	/// ```
+	pub fn abs(value: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.abs() }
+		#[cfg(feature = "no_std")] { f64::from_bits(value.to_bits() & 0x7fff_ffff_ffff_ffff) }
+	}
+	
+	/// Finds if the two floating point numbers are approximately close to each other. Checks with epsilon = 0.000000000001
+	/// - **a**: The first number to check with
+	/// - **b**: The second number to check with
+	/// 
+	/// **Returns**: Returns true if the two values are approximately close to each other
+	/// #### Examples
+	/// ```
+	/// # use mathx::MathF64;
+	/// assert!(MathF64::approx(1.2000000000001, 1.2));
+	/// assert!(!MathF64::approx(1.20000001, 1.2));
+	/// ```
+	pub fn approx(a: f64, b: f64) -> bool {
+		MathF64::abs(a - b) < 0.000000000001
+	}
+	
+	/// Finds if the two floating point numbers are approximately close to each other, provided the epsilon
+	/// - **a**: The first number to check with
+	/// - **b**: The second number to check with
+	/// - **epsilon**: The epsilon (smallest possible difference between numbers) to check with
+	/// 
+	/// **Returns**: Returns true if the two values are approximately close to each other
+	/// #### Examples
+	/// ```
+	/// # use mathx::MathF64;
+	/// assert!(MathF64::approx_epsilon(1.2001, 1.2, 0.001));
+	/// ```
+	pub fn approx_epsilon(a: f64, b: f64, epsilon: f64) -> bool {
+		MathF64::abs(a - b) < epsilon
+	}
+	
+	/// Gets the cosine of the angle in radians
+	/// - **angle**: The angle to compute the cosine with in radians
+	/// 
+	/// **Returns**: Returns the value from the computed cosine
+	/// #### Remarks
+	/// If you need to compute both `cos` and `sin` of the same angle, use `sin_cos` instead as it's more
+	/// performant to produce both values than calling `cos` and `sin` separately
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::cos(0.0);
+	/// assert_range_f64!(1.0, value);
+	/// let value = MathF64::cos(MathF64::PI);
+	/// assert_range_f64!(-1.0, value);
+	/// let value = MathF64::cos(1.0);
+	/// assert_range_f64!(0.5403023058681398, value);
+	/// ```
+	pub fn cos(angle: f64) -> f64 { MathF64::sin_cos(angle).1 }
+	
+	/// Converts the value from degrees to radians
+	/// - **degrees**: The value in degrees to convert
+	/// 
+	/// **Returns**: Returns the value in radians
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::deg2rad(35.0);
+	/// assert_range_f64!(0.6108652381980153, value);
+	/// let value = MathF64::deg2rad(180.0);
+	/// assert_range_f64!(MathF64::PI, value);
+	/// ```
+	pub fn deg2rad(degrees: f64) -> f64 { MathF64::DEG_TO_RAD * degrees }
+	
+	/// Computes e^x
+	/// - **value**: The value to compute with
+	/// 
+	/// **Returns**: Returns the computed e^x
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::exp(0.0);
+	/// assert_range_f64!(1.0, value);
+	/// let value = MathF64::exp(-10.0);
+	/// assert_range_f64!(0.00004539992976248485, value);
+	/// let value = MathF64::exp(10.0);
+	/// assert_range_f64!(22026.465794806718, value, 0.0000000001);
+	/// let value = MathF64::exp(2.9);
+	/// assert_range_f64!(18.17414536944306, value);
+	/// let value = MathF64::exp(1000.0);
+	/// assert!(value.is_infinite());
+	/// ```
+	pub fn exp(value: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.exp() }
+		#[cfg(feature = "no_std")] {
+			const LN2_HI: f64 = 6.931471803691238e-1;
+			const LN2_LO: f64 = 1.9082149292705877e-10;
+			
+			if value.is_nan() { return f64::NAN; }
+			if value > 709.782712893384 { return f64::INFINITY; }
+			if value < -745.1332191019412 { return 0.0; }
+			
+			// Reduces the value to r + k * ln(2) so the series only has to handle |r| <= ln(2) / 2
+			let k = MathF64::floor(value / MathF64::LN2 + 0.5);
+			let r = (value - k * LN2_HI) - k * LN2_LO;
+			let mut result = 1.0;
+			let mut term = 1.0;
+			
+			for n in 1..=20 {
+				term *= r / n as f64;
+				result += term;
+			}
+			
+			MathF64::scale_by_pow2(result, k as i32)
+		}
+	}
+	
+	/// Gets the largest integer number that is less than or equal to the given number
+	/// - **value**: The value to get the floor with
+	/// 
+	/// **Returns**: Returns the floored number
+	/// #### Examples
+	/// ```
+	/// # use mathx::MathF64;
+	/// let value = MathF64::floor(-3.0);
+	/// assert_eq!(-3.0, value);
+	/// let value = MathF64::floor(2.9);
+	/// assert_eq!(2.0, value);
+	/// let value = MathF64::floor(-4.9);
+	/// assert_eq!(-5.0, value);
+	/// ```
+	pub fn floor(value: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.floor() }
+		#[cfg(feature = "no_std")] {
+			let truncated = MathF64::trunc(value);
+			
+			if truncated == value { return truncated; }
+			
+			truncated - if value < 0.0 { 1.0 } else { 0.0 }
+		}
+	}
+	
+	/// Computes the natural log of the given number
+	/// - **value**: The value to compute the natural log of
+	/// 
+	/// **Returns**: Returns the natural log of the given value. Returns `infinity` if the value infinity
+	/// and `-infinity` if the value is 0.0. Returns `NaN` if the value is `NaN` or less than 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::ln(1.0);
+	/// assert_range_f64!(0.0, value);
+	/// let value = MathF64::ln(100.0);
+	/// assert_range_f64!(4.605170185988092, value);
+	/// let value = MathF64::ln(0.01);
+	/// assert_range_f64!(-4.605170185988092, value);
+	/// let value = MathF64::ln(MathF64::E);
+	/// assert_range_f64!(1.0, value);
+	/// let value = MathF64::ln(-10.0);
+	/// assert!(value.is_nan());
+	/// let value = MathF64::ln(0.0);
+	/// assert!(value.is_infinite());
+	/// ```
+	pub fn ln(value: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.ln() }
+		#[cfg(feature = "no_std")] {
+			if value.is_nan() || value < 0.0 { return f64::NAN; }
+			if value == 0.0 { return f64::NEG_INFINITY; }
+			if value.is_infinite() { return f64::INFINITY; }
+			
+			let mut x = value;
+			let mut exponent = 0;
+			
+			// Brings subnormal numbers into the normal range so the exponent can be read from the bits
+			if x < f64::MIN_POSITIVE {
+				x *= 18014398509481984.0;
+				exponent -= 54;
+			}
+			
+			// Splits the value into mantissa * 2^exponent, with the mantissa between sqrt(2) / 2 and sqrt(2)
+			let bits = x.to_bits();
+			let mut mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+			
+			exponent += ((bits >> 52) & 0x7ff) as i32 - 1023;
+			if mantissa > core::f64::consts::SQRT_2 {
+				mantissa /= 2.0;
+				exponent += 1;
+			}
+			
+			// ln(m) = 2 * atanh(s), where s = (m - 1) / (m + 1)
+			let s = (mantissa - 1.0) / (mantissa + 1.0);
+			let s2 = s * s;
+			let mut power = s;
+			let mut series = s;
+			
+			for i in 1..20 {
+				power *= s2;
+				series += power / (2 * i + 1) as f64;
+			}
+			
+			exponent as f64 * MathF64::LN2 + 2.0 * series
+		}
+	}
+	
+	/// Raised the value by the power (as a floating point number)
+	/// - **value**: The value to raise with
+	/// - **power**: The power to raise by
+	/// 
+	/// **Returns**: Returns the value raised by the power
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::pow(1.0, 0.0);
+	/// assert_range_f64!(1.0, value);
+	/// let value = MathF64::pow(2.0, 10.0);
+	/// assert_range_f64!(1024.0, value);
+	/// let value = MathF64::pow(40.0, 1.2);
+	/// assert_range_f64!(83.65116420730185, value, 0.0000000001);
+	/// let value = MathF64::pow(3.0, -2.3);
+	/// assert_range_f64!(0.07991367703609606, value);
+	/// ```
+	pub fn pow(value: f64, power: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.powf(power) }
+		#[cfg(feature = "no_std")] {
+			if power == 0.0 { return 1.0; }
+			if value == 1.0 { return 1.0; }
+			if power == MathF64::trunc(power) && MathF64::abs(power) <= i32::MAX as f64 {
+				return MathF64::pow_i32(value, power as i32);
+			}
+			
+			MathF64::exp(power * MathF64::ln(value))
+		}
+	}
+	
+	/// Gets the power of the given number by the other given number, with the power being an `i32`
+	/// - **a**: The base number to power
+	/// - **b**: The number to power with
+	/// 
+	/// **Returns**: Returns the powered number
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::pow_i32(3.0, 5);
+	/// assert_range_f64!(243.0, value);
+	/// let value = MathF64::pow_i32(10.45, 3);
+	/// assert_range_f64!(1141.166125, value, 0.0000000001);
+	/// let value = MathF64::pow_i32(0.0, 0);
+	/// assert_range_f64!(1.0, value);
+	/// let value = MathF64::pow_i32(2.0, -3);
+	/// assert_range_f64!(0.125, value);
+	/// ```
+	pub fn pow_i32(a: f64, b: i32) -> f64 {
+		#[cfg(not(feature = "no_std"))] { a.powi(b) }
+		#[cfg(feature = "no_std")] {
+			let mut base = a;
+			let mut exp = b.unsigned_abs();
+			let mut result = 1.0;
+			
+			while exp > 0 {
+				if exp & 1 == 1 { result *= base; }
+				base *= base;
+				exp >>= 1;
+			}
+			
+			if b < 0 { result.recip() }
+			else { result }
+		}
+	}
+	
+	/// Converts the value from radians to degrees
+	/// - **radians**: The value in radians to convert
+	/// 
+	/// **Returns**: Returns the value in degrees
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::rad2deg(MathF64::PI_OVER_2);
+	/// assert_range_f64!(90.0, value);
+	/// let value = MathF64::rad2deg(1.0);
+	/// assert_range_f64!(57.29577951308232, value);
+	/// ```
+	pub fn rad2deg(radians: f64) -> f64 { MathF64::RAD_TO_DEG * radians }
+	
+	/// Gets the sine of the angle in radians
+	/// - **angle**: The angle to compute the sine with in radians
+	/// 
+	/// **Returns**: Returns the value from the computed sine
+	/// #### Remarks
+	/// If you need to compute both `cos` and `sin` of the same angle, use `sin_cos` instead as it's more
+	/// performant to produce both values than calling `cos` and `sin` separately
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::sin(0.0);
+	/// assert_range_f64!(0.0, value);
+	/// let value = MathF64::sin(MathF64::PI_OVER_2);
+	/// assert_range_f64!(1.0, value);
+	/// let value = MathF64::sin(1.0);
+	/// assert_range_f64!(0.8414709848078965, value);
+	/// ```
+	pub fn sin(angle: f64) -> f64 { MathF64::sin_cos(angle).0 }
+	
+	/// Computes the sine and cosine of the angle in radians
+	/// - **angle**: The angle to compute the sine and cosine with in radians
+	/// 
+	/// **Returns**: Returns the sine and cosine (respectively) as a tuple
+	/// #### Remarks
+	/// If you need to compute both `cos` and `sin` of the same angle, this function is more
+	/// performant to produce both values than calling `cos` and `sin` separately
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let (sin, cos) = MathF64::sin_cos(0.0);
+	/// assert_range_f64!(0.0, sin);
+	/// assert_range_f64!(1.0, cos);
+	/// let (sin, cos) = MathF64::sin_cos(MathF64::PI);
+	/// assert_range_f64!(0.0, sin);
+	/// assert_range_f64!(-1.0, cos);
+	/// let (sin, cos) = MathF64::sin_cos(MathF64::PI_OVER_4);
+	/// assert_range_f64!(0.7071067811865476, sin);
+	/// assert_range_f64!(0.7071067811865476, cos);
+	/// let (sin, cos) = MathF64::sin_cos(-100.0);
+	/// assert_range_f64!(0.5063656411097588, sin);
+	/// assert_range_f64!(0.8623188722876839, cos);
+	/// ```
+	pub fn sin_cos(angle: f64) -> (f64, f64) {
+		#[cfg(not(feature = "no_std"))] { angle.sin_cos() }
+		#[cfg(feature = "no_std")] {
+			const PI_OVER_2_HI: f64 = 1.5707963267341256;
+			const PI_OVER_2_LO: f64 = 6.077100506506192e-11;
+			
+			if !angle.is_finite() { return (f64::NAN, f64::NAN); }
+			
+			// Reduces the angle to r + k * pi / 2 so the series only has to handle |r| <= pi / 4
+			let k = MathF64::floor(angle / MathF64::PI_OVER_2 + 0.5);
+			let r = (angle - k * PI_OVER_2_HI) - k * PI_OVER_2_LO;
+			let r2 = r * r;
+			let mut sin = r;
+			let mut cos = 1.0;
+			let mut sin_term = r;
+			let mut cos_term = 1.0;
+			
+			for i in 1..12 {
+				sin_term *= -r2 / ((2 * i) * (2 * i + 1)) as f64;
+				cos_term *= -r2 / ((2 * i - 1) * (2 * i)) as f64;
+				sin += sin_term;
+				cos += cos_term;
+			}
+			
+			match (k as i64).rem_euclid(4) {
+				0 => (sin, cos),
+				1 => (cos, -sin),
+				2 => (-sin, -cos),
+				_ => (-cos, sin),
+			}
+		}
+	}
+	
+	/// Gets the square root of the given number
+	/// - **value**: The number to square root
+	/// 
+	/// **Returns**: Returns the square root of the number, returns NaN if `value` is negative
+	/// #### Examples
+	/// ```
+	/// # use mathx::{MathF64,assert_range_f64};
+	/// let value = MathF64::sqrt(16.0);
+	/// assert_range_f64!(4.0, value);
+	/// let value = MathF64::sqrt(2.0);
+	/// assert_range_f64!(1.4142135623730951, value);
+	/// let value = MathF64::sqrt(1023.835);
+	/// assert_range_f64!(31.997421771136498, value);
+	/// let value = MathF64::sqrt(-102.0);
+	/// assert!(value.is_nan());
+	/// let value = MathF64::sqrt(0.0);
+	/// assert_eq!(0.0, value);
+	/// ```
+	pub fn sqrt(value: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.sqrt() }
+		#[cfg(feature = "no_std")] {
+			if value.is_nan() || value < 0.0 { return f64::NAN; }
+			if value == 0.0 || value.is_infinite() { return value; }
+			
+			// Halving the exponent bits gives a close first guess for newton's method
+			let mut x = f64::from_bits((value.to_bits() >> 1) + (1023 << 51));
+			
+			for _ in 0..64 {
+				let next = 0.5 * (x + value / x);
+				
+				if next == x { break; }
+				x = next;
+			}
+			
+			x
+		}
+	}
+	
+	/// Truncates the value of the floating point number
+	/// - **value**: The number to truncate
+	/// 
+	/// **Returns**: Returns the truncated number
+	/// #### Examples
+	/// ```
+	/// # use mathx::MathF64;
+	/// let value = MathF64::trunc(123.456);
+	/// assert_eq!(123.0, value);
+	/// let value = MathF64::trunc(-5.4);
+	/// assert_eq!(-5.0, value);
+	/// let value = MathF64::trunc(10000000000.5);
+	/// assert_eq!(10000000000.0, value);
+	/// ```
+	pub fn trunc(value: f64) -> f64 {
+		#[cfg(not(feature = "no_std"))] { value.trunc() }
+		#[cfg(feature = "no_std")] {
+			// Anything this large is already a whole number, and might not fit into an i64
+			if value.is_nan() || MathF64::abs(value) >= 4503599627370496.0 { return value; }
+			
+			(value as i64) as f64
+		}
+	}
+}
+
+// Private Functions
+impl MathF64 {
+	/// Multiplies the value by 2^exponent by building the power of two from it's bits
+	/// - **value**: The value to scale
+	/// - **exponent**: The power of two to scale by
+	/// 
+	/// **Returns**: Returns the scaled value
+	#[cfg(feature = "no_std")]
+	pub(self) fn scale_by_pow2(value: f64, exponent: i32) -> f64 {
+		let mut value = value;
+		let mut exponent = exponent;
+		
+		while exponent > 1023 {
+			value *= f64::from_bits(2046 << 52);
+			exponent -= 1023;
+		}
+		while exponent < -1022 {
+			value *= f64::from_bits(1 << 52);
+			exponent += 1022;
+		}
+		
+		value * f64::from_bits(((exponent + 1023) as u64) << 52)
+	}
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_range_f64 {
+	($expected:expr, $value:expr) => {
+		assert_range_f64!($expected, $value, 0.000000000001);
+	};
+	($expected:expr, $value:expr, $epsilon:expr) => {
+		if !MathF64::approx_epsilon($expected, $value, $epsilon) { panic!("\n\nleft: {:?}\nright: {:?}\n\n", $expected, $value); }
+	};
+}
@@ -0,0 +1,336 @@
+
+use core::ops::Mul;
+
+use crate::Math;
+#[cfg(not(feature = "no_vectors"))]
+use crate::Vector2;
+use crate::impl_mul;
+
+/// A 3x3 matrix that holds 9 values stored in row-major order, useful for 2D transforms and normal matrices
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix3x3 {
+	/// The values of the matrix, stored row by row
+	values: [f32; 9],
+}
+
+/// Constructors
+impl Matrix3x3 {
+	/// Creates a new 3x3 matrix from the given values
+	/// - **values**: The 9 values of the matrix in row-major order (the first 3 values are the first row)
+	/// 
+	/// **Returns**: Returns a new 3x3 matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::new([
+	///     1.0, 2.0, 3.0,
+	///     4.0, 5.0, 6.0,
+	///     7.0, 8.0, 9.0,
+	/// ]);
+	/// assert_eq!(2.0, matrix.get(0, 1));
+	/// assert_eq!(4.0, matrix.get(1, 0));
+	/// assert_eq!(9.0, matrix.get(2, 2));
+	/// ```
+	pub fn new(values: [f32; 9]) -> Self { Matrix3x3 { values } }
+	
+	/// Creates the identity matrix, which leaves anything it multiplies the same
+	/// 
+	/// **Returns**: Returns the identity matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::identity();
+	/// assert_eq!(1.0, matrix.get(0, 0));
+	/// assert_eq!(0.0, matrix.get(0, 1));
+	/// assert_eq!(1.0, matrix.get(2, 2));
+	/// ```
+	pub fn identity() -> Self {
+		Matrix3x3::new([
+			1.0, 0.0, 0.0,
+			0.0, 1.0, 0.0,
+			0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a matrix filled with zeroes
+	/// 
+	/// **Returns**: Returns a matrix filled with zeroes
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::zero();
+	/// assert_eq!(0.0, matrix.get(0, 0));
+	/// assert_eq!(0.0, matrix.get(2, 2));
+	/// ```
+	pub fn zero() -> Self { Matrix3x3::new([0.0; 9]) }
+}
+
+/// Properties
+impl Matrix3x3 {
+	/// Gets the value of the matrix at the given row and column
+	/// - **row**: The row of the value, between 0 and 2
+	/// - **col**: The column of the value, between 0 and 2
+	/// 
+	/// **Returns**: Returns the value at the given row and column
+	/// #### Remarks
+	/// This panics if either the row or the column is out of bounds
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::identity();
+	/// assert_eq!(1.0, matrix.get(2, 2));
+	/// assert_eq!(0.0, matrix.get(1, 2));
+	/// ```
+	pub fn get(&self, row: usize, col: usize) -> f32 {
+		assert!(row < 3 && col < 3, "matrix index out of bounds: ({}, {})", row, col);
+		self.values[3 * row + col]
+	}
+	
+	/// Sets the value of the matrix at the given row and column
+	/// - **row**: The row of the value, between 0 and 2
+	/// - **col**: The column of the value, between 0 and 2
+	/// - **value**: The value to set
+	/// #### Remarks
+	/// This panics if either the row or the column is out of bounds
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let mut matrix = Matrix3x3::identity();
+	/// matrix.set(0, 2, 5.0);
+	/// assert_eq!(5.0, matrix.get(0, 2));
+	/// ```
+	pub fn set(&mut self, row: usize, col: usize, value: f32) {
+		assert!(row < 3 && col < 3, "matrix index out of bounds: ({}, {})", row, col);
+		self.values[3 * row + col] = value;
+	}
+}
+
+/// Public Methods
+impl Matrix3x3 {
+	/// Gets the determinant of the matrix
+	/// 
+	/// **Returns**: Returns the determinant of the matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::new([
+	///     2.0, 0.0, 1.0,
+	///     1.0, 3.0, 2.0,
+	///     1.0, 1.0, 2.0,
+	/// ]);
+	/// assert_eq!(6.0, matrix.determinant());
+	/// assert_eq!(1.0, Matrix3x3::identity().determinant());
+	/// ```
+	pub fn determinant(&self) -> f32 {
+		let v = &self.values;
+		
+		v[0] * (v[4] * v[8] - v[5] * v[7])
+		- v[1] * (v[3] * v[8] - v[5] * v[6])
+		+ v[2] * (v[3] * v[7] - v[4] * v[6])
+	}
+	
+	/// Gets the inverse of the matrix, which undoes anything the matrix does when multiplied together
+	/// 
+	/// **Returns**: Returns the inverse of the matrix, returns `None` if the determinant is approximately zero
+	/// #### Remarks
+	/// The determinant is compared against the size of the largest value within the matrix,
+	/// so that matrices with small values can still be inverted
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::new([
+	///     2.0, 0.0, 1.0,
+	///     1.0, 3.0, 2.0,
+	///     1.0, 1.0, 2.0,
+	/// ]);
+	/// let inverse = matrix.inverse().unwrap();
+	/// assert_eq!(Matrix3x3::identity(), matrix * inverse);
+	/// assert_eq!(Matrix3x3::identity(), inverse * matrix);
+	/// let singular = Matrix3x3::new([
+	///     1.0, 2.0, 3.0,
+	///     4.0, 5.0, 6.0,
+	///     7.0, 8.0, 9.0,
+	/// ]);
+	/// assert_eq!(None, singular.inverse());
+	/// let small = Matrix3x3::new([
+	///     0.01, 0.0, 0.005,
+	///     0.005, 0.015, 0.01,
+	///     0.005, 0.005, 0.01,
+	/// ]);
+	/// let inverse = small.inverse().unwrap();
+	/// assert_eq!(Matrix3x3::identity(), small * inverse);
+	/// assert_eq!(Matrix3x3::identity(), inverse * small);
+	/// let scale = Matrix3x3::new([
+	///     0.005, 0.0, 0.0,
+	///     0.0, 0.005, 0.0,
+	///     0.0, 0.0, 0.005,
+	/// ]);
+	/// assert_eq!(Some(Matrix3x3::new([200.0, 0.0, 0.0, 0.0, 200.0, 0.0, 0.0, 0.0, 200.0])), scale.inverse());
+	/// assert_eq!(None, Matrix3x3::zero().inverse());
+	/// ```
+	pub fn inverse(self) -> Option<Self> {
+		let determinant = self.determinant();
+		let scale = self.values.iter().fold(0.0, |scale, value| Math::max(scale, Math::abs(*value)));
+		
+		if Math::abs(determinant) <= 0.000001 * scale * scale * scale { return None; }
+		
+		let v = &self.values;
+		let inverse_determinant = 1.0 / determinant;
+		let mut values = [
+			v[4] * v[8] - v[5] * v[7], v[2] * v[7] - v[1] * v[8], v[1] * v[5] - v[2] * v[4],
+			v[5] * v[6] - v[3] * v[8], v[0] * v[8] - v[2] * v[6], v[2] * v[3] - v[0] * v[5],
+			v[3] * v[7] - v[4] * v[6], v[1] * v[6] - v[0] * v[7], v[0] * v[4] - v[1] * v[3],
+		];
+		
+		for value in values.iter_mut() {
+			*value *= inverse_determinant;
+		}
+		
+		Some(Matrix3x3::new(values))
+	}
+	
+	/// Multiplies this matrix with the other matrix
+	/// - **rhs**: The matrix to multiply with on the right hand side
+	/// 
+	/// **Returns**: Returns the multiplied matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let a = Matrix3x3::new([
+	///     1.0, 2.0, 3.0,
+	///     4.0, 5.0, 6.0,
+	///     7.0, 8.0, 9.0,
+	/// ]);
+	/// let b = Matrix3x3::new([
+	///     1.0, 0.0, 1.0,
+	///     0.0, 2.0, 0.0,
+	///     0.0, 0.0, 3.0,
+	/// ]);
+	/// let expected = Matrix3x3::new([
+	///     1.0, 4.0, 10.0,
+	///     4.0, 10.0, 22.0,
+	///     7.0, 16.0, 34.0,
+	/// ]);
+	/// assert_eq!(expected, a.multiply(b));
+	/// assert_eq!(expected, a * b);
+	/// assert_eq!(a, Matrix3x3::identity() * a);
+	/// assert_eq!(a, a * Matrix3x3::identity());
+	/// ```
+	pub fn multiply(self, rhs: Matrix3x3) -> Self {
+		let mut values = [0.0; 9];
+		
+		for row in 0..3 {
+			for col in 0..3 {
+				values[3 * row + col] = self.values[3 * row] * rhs.values[col]
+					+ self.values[3 * row + 1] * rhs.values[3 + col]
+					+ self.values[3 * row + 2] * rhs.values[6 + col];
+			}
+		}
+		
+		Matrix3x3::new(values)
+	}
+	
+	/// Transforms the point using this matrix as a 2D affine transform, treating the point as having a z component of 1
+	/// - **point**: The point to transform
+	/// 
+	/// **Returns**: Returns the transformed point
+	/// #### Remarks
+	/// If the resulting z component is neither 0 nor 1 (such as with a projective matrix),
+	/// then the point gets divided by it to bring it back into 2D space
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix3x3,Vector2};
+	/// let matrix = Matrix3x3::new([
+	///     2.0, 0.0, 10.0,
+	///     0.0, 3.0, 20.0,
+	///     0.0, 0.0, 1.0,
+	/// ]);
+	/// let point = matrix.transform_point(Vector2::one());
+	/// assert_eq!(Vector2::new(12.0, 23.0), point);
+	/// assert_eq!(point, matrix * Vector2::one());
+	/// assert_eq!(Vector2::one(), Matrix3x3::identity() * Vector2::one());
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn transform_point(self, point: Vector2) -> Vector2 {
+		let v = &self.values;
+		let transformed = Vector2::new(
+			v[0] * point.x() + v[1] * point.y() + v[2],
+			v[3] * point.x() + v[4] * point.y() + v[5]
+		);
+		let w = v[6] * point.x() + v[7] * point.y() + v[8];
+		
+		if w == 0.0 || w == 1.0 { transformed }
+		else { transformed / w }
+	}
+	
+	/// Transposes the matrix, swapping the rows with the columns
+	/// 
+	/// **Returns**: Returns the transposed matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix3x3;
+	/// let matrix = Matrix3x3::new([
+	///     1.0, 2.0, 3.0,
+	///     4.0, 5.0, 6.0,
+	///     7.0, 8.0, 9.0,
+	/// ]);
+	/// let transposed = matrix.transpose();
+	/// assert_eq!(4.0, transposed.get(0, 1));
+	/// assert_eq!(2.0, transposed.get(1, 0));
+	/// assert_eq!(matrix, transposed.transpose());
+	/// ```
+	pub fn transpose(self) -> Self {
+		let mut values = [0.0; 9];
+		
+		for row in 0..3 {
+			for col in 0..3 {
+				values[3 * col + row] = self.values[3 * row + col];
+			}
+		}
+		
+		Matrix3x3::new(values)
+	}
+}
+
+unsafe impl Send for Matrix3x3 {}
+unsafe impl Sync for Matrix3x3 {}
+
+// Default
+/// Gets the identity matrix, the same as `Matrix3x3::identity()`
+/// #### Examples
+/// ```
+/// # use mathx::Matrix3x3;
+/// assert_eq!(Matrix3x3::identity(), Matrix3x3::default());
+/// ```
+impl Default for Matrix3x3 {
+	fn default() -> Self { Matrix3x3::identity() }
+}
+
+// Equates
+impl Eq for Matrix3x3 {}
+impl PartialEq for Matrix3x3 {
+	fn eq(&self, other: &Self) -> bool {
+		self.values.iter()
+			.zip(other.values.iter())
+			.all(|(a, b)| Math::approx(*a, *b))
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Matrix3x3 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let v = &self.values;
+		f.write_str(&format!(
+			"[({}, {}, {}), ({}, {}, {}), ({}, {}, {})]",
+			v[0], v[1], v[2],
+			v[3], v[4], v[5],
+			v[6], v[7], v[8]
+		))
+	}
+}
+
+impl_mul!(Matrix3x3, Matrix3x3 => Matrix3x3: multiply);
+#[cfg(not(feature = "no_vectors"))]
+impl_mul!(Matrix3x3, Vector2 => Vector2: transform_point);
@@ -0,0 +1,508 @@
+
+use core::ops::Mul;
+
+use crate::Math;
+#[cfg(not(feature = "no_vectors"))]
+use crate::Vector3;
+#[cfg(not(feature = "no_quaternions"))]
+use crate::Quaternion;
+use crate::impl_mul;
+
+/// A 4x4 matrix that holds 16 values stored in row-major order
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Matrix4x4 {
+	/// The values of the matrix, stored row by row
+	values: [f32; 16],
+}
+
+/// Constructors
+impl Matrix4x4 {
+	/// Creates a new 4x4 matrix from the given values
+	/// - **values**: The 16 values of the matrix in row-major order (the first 4 values are the first row)
+	/// 
+	/// **Returns**: Returns a new 4x4 matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let matrix = Matrix4x4::new([
+	///     1.0, 2.0, 3.0, 4.0,
+	///     5.0, 6.0, 7.0, 8.0,
+	///     9.0, 10.0, 11.0, 12.0,
+	///     13.0, 14.0, 15.0, 16.0,
+	/// ]);
+	/// assert_eq!(2.0, matrix.get(0, 1));
+	/// assert_eq!(5.0, matrix.get(1, 0));
+	/// assert_eq!(16.0, matrix.get(3, 3));
+	/// ```
+	pub fn new(values: [f32; 16]) -> Self { Matrix4x4 { values } }
+	
+	/// Creates the identity matrix, which leaves anything it multiplies the same
+	/// 
+	/// **Returns**: Returns the identity matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let matrix = Matrix4x4::identity();
+	/// assert_eq!(1.0, matrix.get(0, 0));
+	/// assert_eq!(0.0, matrix.get(0, 1));
+	/// assert_eq!(1.0, matrix.get(3, 3));
+	/// ```
+	pub fn identity() -> Self {
+		Matrix4x4::new([
+			1.0, 0.0, 0.0, 0.0,
+			0.0, 1.0, 0.0, 0.0,
+			0.0, 0.0, 1.0, 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a matrix filled with zeroes
+	/// 
+	/// **Returns**: Returns a matrix filled with zeroes
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let matrix = Matrix4x4::zero();
+	/// assert_eq!(0.0, matrix.get(0, 0));
+	/// assert_eq!(0.0, matrix.get(3, 3));
+	/// ```
+	pub fn zero() -> Self { Matrix4x4::new([0.0; 16]) }
+	
+	/// Creates a rotation matrix from the given quaternion
+	/// - **rotation**: The rotation of the matrix
+	/// 
+	/// **Returns**: Returns a rotation matrix, the same as `rotation.to_matrix()`
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Matrix4x4,Quaternion,Vector3};
+	/// let rotation = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// let matrix = Matrix4x4::from_rotation(rotation);
+	/// assert_eq!(rotation * Vector3::right(), matrix * Vector3::right());
+	/// assert_eq!(Matrix4x4::identity(), Matrix4x4::from_rotation(Quaternion::identity()));
+	/// # }
+	/// ```
+	#[cfg(not(feature = "no_quaternions"))]
+	pub fn from_rotation(rotation: Quaternion) -> Self { rotation.to_matrix() }
+	
+	/// Creates a scaling matrix from the given scale
+	/// - **scale**: The scale of each axis
+	/// 
+	/// **Returns**: Returns a scaling matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4x4,Vector3};
+	/// let matrix = Matrix4x4::from_scale(Vector3::new(2.0, 3.0, 4.0));
+	/// assert_eq!(Vector3::new(2.0, 3.0, 4.0), matrix.transform_point(Vector3::one()));
+	/// assert_eq!(Vector3::zero(), matrix.transform_point(Vector3::zero()));
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn from_scale(scale: Vector3) -> Self {
+		Matrix4x4::new([
+			scale.x(), 0.0, 0.0, 0.0,
+			0.0, scale.y(), 0.0, 0.0,
+			0.0, 0.0, scale.z(), 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a translation matrix from the given translation
+	/// - **translation**: The translation of the matrix
+	/// 
+	/// **Returns**: Returns a translation matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4x4,Vector3};
+	/// let translation = Vector3::new(1.0, -2.0, 3.0);
+	/// let matrix = Matrix4x4::from_translation(translation);
+	/// assert_eq!(translation, matrix.transform_point(Vector3::zero()));
+	/// assert_eq!(Vector3::new(2.0, -1.0, 4.0), matrix.transform_point(Vector3::one()));
+	/// assert_eq!(Vector3::one(), matrix.transform_direction(Vector3::one()));
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn from_translation(translation: Vector3) -> Self {
+		Matrix4x4::new([
+			1.0, 0.0, 0.0, translation.x(),
+			0.0, 1.0, 0.0, translation.y(),
+			0.0, 0.0, 1.0, translation.z(),
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a transformation matrix from the given translation, rotation and scale
+	/// - **translation**: The translation of the matrix
+	/// - **rotation**: The rotation of the matrix
+	/// - **scale**: The scale of each axis
+	/// 
+	/// **Returns**: Returns a transformation matrix
+	/// #### Remarks
+	/// The matrix is composed as `T * R * S`, so points are scaled first, then rotated and then translated
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4x4,Quaternion,Vector3};
+	/// let translation = Vector3::new(10.0, 0.0, 0.0);
+	/// let rotation = Quaternion::from_axis_angle_deg(Vector3::forward(), 90.0);
+	/// let scale = Vector3::new(2.0, 1.0, 1.0);
+	/// let matrix = Matrix4x4::from_trs(translation, rotation, scale);
+	/// let expected = Matrix4x4::from_translation(translation)
+	///     * Matrix4x4::from_rotation(rotation)
+	///     * Matrix4x4::from_scale(scale);
+	/// assert_eq!(expected, matrix);
+	/// let point = Vector3::right();
+	/// assert_eq!(translation + rotation * (2.0 * point), matrix * point);
+	/// ```
+	#[cfg(not(any(feature = "no_vectors", feature = "no_quaternions")))]
+	pub fn from_trs(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+		Matrix4x4::from_translation(translation)
+			* Matrix4x4::from_rotation(rotation)
+			* Matrix4x4::from_scale(scale)
+	}
+	
+	/// Creates a view matrix that places the eye at the origin looking towards the target
+	/// - **eye**: The position the view is looking from
+	/// - **target**: The position the view is looking at
+	/// - **up**: The direction that is considered up for the view
+	/// 
+	/// **Returns**: Returns a view matrix
+	/// #### Remarks
+	/// This uses a right-handed view space where the target ends up along the negative z axis,
+	/// matching `Matrix4x4::perspective` and `Matrix4x4::orthographic`.
+	/// If the eye and the target are the same then there is no direction to look towards,
+	/// so the matrix only translates the eye to the origin. If the direction to the target is parallel
+	/// to the up direction, then a different up direction is used instead, the same as `Quaternion::look_rotation`
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4x4,Vector3};
+	/// let eye = Vector3::new(1.0, 2.0, 3.0);
+	/// let target = Vector3::new(1.0, 2.0, -7.0);
+	/// let matrix = Matrix4x4::look_at(eye, target, Vector3::up());
+	/// assert_eq!(Vector3::zero(), matrix.transform_point(eye));
+	/// assert_eq!(Vector3::new(0.0, 0.0, -10.0), matrix.transform_point(target));
+	/// let matrix = Matrix4x4::look_at(Vector3::zero(), Vector3::new(5.0, 0.0, 0.0), Vector3::up());
+	/// assert_eq!(Vector3::new(0.0, 0.0, -5.0), matrix.transform_point(Vector3::new(5.0, 0.0, 0.0)));
+	/// assert_eq!(Vector3::new(0.0, 1.0, 0.0), matrix.transform_direction(Vector3::up()));
+	/// let matrix = Matrix4x4::look_at(eye, eye, Vector3::up());
+	/// assert_eq!(Matrix4x4::from_translation(-eye), matrix);
+	/// let eye = Vector3::new(0.0, 5.0, 0.0);
+	/// let matrix = Matrix4x4::look_at(eye, Vector3::zero(), Vector3::up());
+	/// assert_eq!(Vector3::zero(), matrix.transform_point(eye));
+	/// assert_eq!(Vector3::new(0.0, 0.0, -5.0), matrix.transform_point(Vector3::zero()));
+	/// assert_eq!(Vector3::new(1.0, 0.0, -5.0), matrix.transform_point(Vector3::new(1.0, 0.0, 0.0)));
+	/// assert_eq!(Vector3::new(0.0, 0.0, 1.0), matrix.transform_direction(Vector3::up()));
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn look_at(eye: Vector3, target: Vector3, up: Vector3) -> Self {
+		if eye == target { return Matrix4x4::from_translation(-eye); }
+		
+		let forward = (target - eye).normalize();
+		let mut right = forward.cross(up);
+		
+		if right.square_magnitude() < 0.000001 { right = forward.cross(Vector3::back()); }
+		if right.square_magnitude() < 0.000001 { right = forward.cross(Vector3::up()); }
+		
+		let right = right.normalize();
+		let up = right.cross(forward);
+		
+		Matrix4x4::new([
+			right.x(), right.y(), right.z(), -right.dot(eye),
+			up.x(), up.y(), up.z(), -up.dot(eye),
+			-forward.x(), -forward.y(), -forward.z(), forward.dot(eye),
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates an orthographic projection matrix
+	/// - **left**: The position of the left side of the view volume
+	/// - **right**: The position of the right side of the view volume
+	/// - **bottom**: The position of the bottom side of the view volume
+	/// - **top**: The position of the top side of the view volume
+	/// - **near**: The distance to the near clipping plane
+	/// - **far**: The distance to the far clipping plane
+	/// 
+	/// **Returns**: Returns an orthographic projection matrix
+	/// #### Remarks
+	/// This uses a right-handed view space where the camera looks down the negative z axis,
+	/// mapping the view volume into clip space where x, y and z all range from -1 to 1.
+	/// A point at the near plane maps to a depth of -1 and a point at the far plane maps to a depth of 1
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Matrix4x4,Vector3,Math,assert_range};
+	/// let matrix = Matrix4x4::orthographic(-4.0, 4.0, -2.0, 2.0, 0.5, 10.0);
+	/// let point = matrix.transform_point(Vector3::new(-4.0, 2.0, -0.5));
+	/// assert_eq!(Vector3::new(-1.0, 1.0, -1.0), point);
+	/// let point = matrix.transform_point(Vector3::new(2.0, -1.0, -10.0));
+	/// assert_range!(0.5, point.x());
+	/// assert_range!(-0.5, point.y());
+	/// assert_range!(1.0, point.z());
+	/// # }
+	/// ```
+	pub fn orthographic(left: f32, right: f32, bottom: f32, top: f32, near: f32, far: f32) -> Self {
+		let width = right - left;
+		let height = top - bottom;
+		let depth = far - near;
+		
+		Matrix4x4::new([
+			2.0 / width, 0.0, 0.0, -(right + left) / width,
+			0.0, 2.0 / height, 0.0, -(top + bottom) / height,
+			0.0, 0.0, -2.0 / depth, -(far + near) / depth,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Creates a perspective projection matrix
+	/// - **fov_y**: The vertical field of view in radians
+	/// - **aspect**: The aspect ratio of the view, which is the width divided by the height
+	/// - **near**: The distance to the near clipping plane
+	/// - **far**: The distance to the far clipping plane
+	/// 
+	/// **Returns**: Returns a perspective projection matrix
+	/// #### Remarks
+	/// This uses a right-handed view space where the camera looks down the negative z axis,
+	/// mapping the view frustum into clip space where x, y and z all range from -1 to 1.
+	/// A point at the near plane maps to a depth of -1 and a point at the far plane maps to a depth of 1
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Matrix4x4,Vector3,Math,assert_range};
+	/// let matrix = Matrix4x4::perspective(Math::PI_OVER_2, 2.0, 0.1, 100.0);
+	/// let point = matrix.transform_point(Vector3::new(0.0, 0.0, -0.1));
+	/// assert_range!(-1.0, point.z());
+	/// let point = matrix.transform_point(Vector3::new(0.0, 0.0, -100.0));
+	/// assert_range!(1.0, point.z());
+	/// let point = matrix.transform_point(Vector3::new(10.0, -5.0, -5.0));
+	/// assert_range!(1.0, point.x());
+	/// assert_range!(-1.0, point.y());
+	/// # }
+	/// ```
+	pub fn perspective(fov_y: f32, aspect: f32, near: f32, far: f32) -> Self {
+		let focal = 1.0 / Math::tan(0.5 * fov_y);
+		let depth = near - far;
+		
+		Matrix4x4::new([
+			focal / aspect, 0.0, 0.0, 0.0,
+			0.0, focal, 0.0, 0.0,
+			0.0, 0.0, (far + near) / depth, 2.0 * far * near / depth,
+			0.0, 0.0, -1.0, 0.0,
+		])
+	}
+}
+
+/// Properties
+impl Matrix4x4 {
+	/// Gets the value of the matrix at the given row and column
+	/// - **row**: The row of the value, between 0 and 3
+	/// - **col**: The column of the value, between 0 and 3
+	/// 
+	/// **Returns**: Returns the value at the given row and column
+	/// #### Remarks
+	/// This panics if either the row or the column is out of bounds
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let matrix = Matrix4x4::identity();
+	/// assert_eq!(1.0, matrix.get(2, 2));
+	/// assert_eq!(0.0, matrix.get(2, 3));
+	/// ```
+	pub fn get(&self, row: usize, col: usize) -> f32 {
+		assert!(row < 4 && col < 4, "matrix index out of bounds: ({}, {})", row, col);
+		self.values[4 * row + col]
+	}
+	
+	/// Sets the value of the matrix at the given row and column
+	/// - **row**: The row of the value, between 0 and 3
+	/// - **col**: The column of the value, between 0 and 3
+	/// - **value**: The value to set
+	/// #### Remarks
+	/// This panics if either the row or the column is out of bounds
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let mut matrix = Matrix4x4::identity();
+	/// matrix.set(0, 3, 5.0);
+	/// assert_eq!(5.0, matrix.get(0, 3));
+	/// ```
+	pub fn set(&mut self, row: usize, col: usize, value: f32) {
+		assert!(row < 4 && col < 4, "matrix index out of bounds: ({}, {})", row, col);
+		self.values[4 * row + col] = value;
+	}
+}
+
+/// Public Methods
+impl Matrix4x4 {
+	/// Multiplies this matrix with the other matrix
+	/// - **rhs**: The matrix to multiply with on the right hand side
+	/// 
+	/// **Returns**: Returns the multiplied matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let a = Matrix4x4::new([
+	///     1.0, 2.0, 3.0, 4.0,
+	///     5.0, 6.0, 7.0, 8.0,
+	///     9.0, 10.0, 11.0, 12.0,
+	///     13.0, 14.0, 15.0, 16.0,
+	/// ]);
+	/// let b = Matrix4x4::new([
+	///     1.0, 0.0, 0.0, 1.0,
+	///     0.0, 2.0, 0.0, 0.0,
+	///     0.0, 0.0, 3.0, 0.0,
+	///     0.0, 0.0, 0.0, 1.0,
+	/// ]);
+	/// let expected = Matrix4x4::new([
+	///     1.0, 4.0, 9.0, 5.0,
+	///     5.0, 12.0, 21.0, 13.0,
+	///     9.0, 20.0, 33.0, 21.0,
+	///     13.0, 28.0, 45.0, 29.0,
+	/// ]);
+	/// assert_eq!(expected, a.multiply(b));
+	/// assert_eq!(expected, a * b);
+	/// assert_eq!(a, Matrix4x4::identity() * a);
+	/// assert_eq!(a, a * Matrix4x4::identity());
+	/// ```
+	pub fn multiply(self, rhs: Matrix4x4) -> Self {
+		let mut values = [0.0; 16];
+		
+		for row in 0..4 {
+			for col in 0..4 {
+				values[4 * row + col] = self.values[4 * row] * rhs.values[col]
+					+ self.values[4 * row + 1] * rhs.values[4 + col]
+					+ self.values[4 * row + 2] * rhs.values[8 + col]
+					+ self.values[4 * row + 3] * rhs.values[12 + col];
+			}
+		}
+		
+		Matrix4x4::new(values)
+	}
+	
+	/// Transforms the direction using this matrix, ignoring any translation within the matrix
+	/// - **direction**: The direction to transform
+	/// 
+	/// **Returns**: Returns the transformed direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4x4,Vector3};
+	/// let matrix = Matrix4x4::new([
+	///     2.0, 0.0, 0.0, 10.0,
+	///     0.0, 3.0, 0.0, 20.0,
+	///     0.0, 0.0, 4.0, 30.0,
+	///     0.0, 0.0, 0.0, 1.0,
+	/// ]);
+	/// let direction = matrix.transform_direction(Vector3::one());
+	/// assert_eq!(Vector3::new(2.0, 3.0, 4.0), direction);
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn transform_direction(self, direction: Vector3) -> Vector3 {
+		let v = &self.values;
+		
+		Vector3::new(
+			v[0] * direction.x() + v[1] * direction.y() + v[2] * direction.z(),
+			v[4] * direction.x() + v[5] * direction.y() + v[6] * direction.z(),
+			v[8] * direction.x() + v[9] * direction.y() + v[10] * direction.z()
+		)
+	}
+	
+	/// Transforms the point using this matrix, treating the point as having a w component of 1
+	/// - **point**: The point to transform
+	/// 
+	/// **Returns**: Returns the transformed point
+	/// #### Remarks
+	/// If the resulting w component is neither 0 nor 1 (such as with a perspective matrix),
+	/// then the point gets divided by it to bring it back into 3D space
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Matrix4x4,Vector3};
+	/// let matrix = Matrix4x4::new([
+	///     2.0, 0.0, 0.0, 10.0,
+	///     0.0, 3.0, 0.0, 20.0,
+	///     0.0, 0.0, 4.0, 30.0,
+	///     0.0, 0.0, 0.0, 1.0,
+	/// ]);
+	/// let point = matrix.transform_point(Vector3::one());
+	/// assert_eq!(Vector3::new(12.0, 23.0, 34.0), point);
+	/// assert_eq!(point, matrix * Vector3::one());
+	/// assert_eq!(Vector3::one(), Matrix4x4::identity() * Vector3::one());
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn transform_point(self, point: Vector3) -> Vector3 {
+		let v = &self.values;
+		let transformed = self.transform_direction(point) + Vector3::new(v[3], v[7], v[11]);
+		let w = v[12] * point.x() + v[13] * point.y() + v[14] * point.z() + v[15];
+		
+		if w == 0.0 || w == 1.0 { transformed }
+		else { transformed / w }
+	}
+	
+	/// Transposes the matrix, swapping the rows with the columns
+	/// 
+	/// **Returns**: Returns the transposed matrix
+	/// #### Examples
+	/// ```
+	/// # use mathx::Matrix4x4;
+	/// let matrix = Matrix4x4::new([
+	///     1.0, 2.0, 3.0, 4.0,
+	///     5.0, 6.0, 7.0, 8.0,
+	///     9.0, 10.0, 11.0, 12.0,
+	///     13.0, 14.0, 15.0, 16.0,
+	/// ]);
+	/// let transposed = matrix.transpose();
+	/// assert_eq!(5.0, transposed.get(0, 1));
+	/// assert_eq!(2.0, transposed.get(1, 0));
+	/// assert_eq!(matrix, transposed.transpose());
+	/// ```
+	pub fn transpose(self) -> Self {
+		let mut values = [0.0; 16];
+		
+		for row in 0..4 {
+			for col in 0..4 {
+				values[4 * col + row] = self.values[4 * row + col];
+			}
+		}
+		
+		Matrix4x4::new(values)
+	}
+}
+
+unsafe impl Send for Matrix4x4 {}
+unsafe impl Sync for Matrix4x4 {}
+
+// Default
+/// Gets the identity matrix, the same as `Matrix4x4::identity()`
+/// #### Examples
+/// ```
+/// # use mathx::Matrix4x4;
+/// assert_eq!(Matrix4x4::identity(), Matrix4x4::default());
+/// ```
+impl Default for Matrix4x4 {
+	fn default() -> Self { Matrix4x4::identity() }
+}
+
+// Equates
+impl Eq for Matrix4x4 {}
+impl PartialEq for Matrix4x4 {
+	fn eq(&self, other: &Self) -> bool {
+		self.values.iter()
+			.zip(other.values.iter())
+			.all(|(a, b)| Math::approx(*a, *b))
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Matrix4x4 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let v = &self.values;
+		f.write_str(&format!(
+			"[({}, {}, {}, {}), ({}, {}, {}, {}), ({}, {}, {}, {}), ({}, {}, {}, {})]",
+			v[0], v[1], v[2], v[3],
+			v[4], v[5], v[6], v[7],
+			v[8], v[9], v[10], v[11],
+			v[12], v[13], v[14], v[15]
+		))
+	}
+}
+
+impl_mul!(Matrix4x4, Matrix4x4 => Matrix4x4: multiply);
+#[cfg(not(feature = "no_vectors"))]
+impl_mul!(Matrix4x4, Vector3 => Vector3: transform_point);
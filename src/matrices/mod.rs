@@ -0,0 +1,4 @@
+mod matrix3x3;
+pub use matrix3x3::Matrix3x3;
+mod matrix4x4;
+pub use matrix4x4::Matrix4x4;
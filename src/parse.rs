@@ -0,0 +1,66 @@
+
+/// The error returned when parsing a structure from a string fails
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+	/// Only one of the surrounding parentheses was given
+	UnbalancedParentheses,
+	/// The wrong number of comma-separated components was given
+	WrongCount {
+		/// The number of components that were expected
+		expected: usize,
+		/// The number of components that were found
+		found: usize,
+	},
+	/// The component at the given index could not be parsed as a number
+	InvalidNumber {
+		/// The index of the component that could not be parsed
+		index: usize,
+	},
+	/// The text is neither a known color name, a hex code, nor a list of numbers
+	UnknownColor,
+}
+
+impl core::fmt::Display for ParseError {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		match self {
+			ParseError::UnbalancedParentheses => f.write_str("unbalanced parentheses"),
+			ParseError::WrongCount { expected, found } => write!(f, "expected {} components but found {}", expected, found),
+			ParseError::InvalidNumber { index } => write!(f, "component {} is not a valid number", index),
+			ParseError::UnknownColor => f.write_str("unknown color name or hex code"),
+		}
+	}
+}
+
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for ParseError {}
+
+/// Parses a list of comma-separated numbers that can be surrounded by parentheses
+/// - **text**: The text to parse
+/// - **suffixes**: The optional suffix of each component (such as the `i` in `2i`), use `""` for no suffix
+/// 
+/// **Returns**: Returns the parsed numbers or the reason why they couldn't be parsed
+pub(crate) fn parse_floats<const N: usize>(text: &str, suffixes: [&str; N]) -> Result<[f32; N], ParseError> {
+	let text = text.trim();
+	let text = match (text.strip_prefix('('), text.strip_suffix(')')) {
+		(Some(_), Some(_)) => &text[1..text.len() - 1],
+		(None, None) => text,
+		_ => return Err(ParseError::UnbalancedParentheses),
+	};
+	let found = text.split(',').count();
+	
+	if found != N { return Err(ParseError::WrongCount { expected: N, found }); }
+	
+	let mut values = [0.0; N];
+	
+	for (index, component) in text.split(',').enumerate() {
+		let component = component.trim();
+		let component = component.strip_suffix(suffixes[index]).unwrap_or(component).trim_end();
+		
+		values[index] = match component.parse::<f32>() {
+			Ok(value) => value,
+			Err(_) => return Err(ParseError::InvalidNumber { index }),
+		};
+	}
+	
+	Ok(values)
+}
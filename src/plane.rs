@@ -1,8 +1,10 @@
 
 use core::ops::Neg;
-use crate::Vector3;
+use crate::{Vector3, Math};
 #[cfg(not(feature = "no_rays"))]
-use crate::{Ray3, Math, interfaces::IRaycast, collision::{RaycastInfo, RaycastInfoBuilder}};
+use crate::Ray3;
+#[cfg(not(any(feature = "no_rays", feature = "no_collision")))]
+use crate::{interfaces::IRaycast, collision::{RaycastInfo, RaycastInfoBuilder}};
 
 /// A struct that represents a 3D plane
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -250,14 +252,91 @@ impl Plane {
 	pub fn is_on_same_side(&self, a: Vector3, b: Vector3) -> bool {
 		self.is_on_positive_side(a) == self.is_on_positive_side(b)
 	}
+	
+	/// Gets the point where the ray intersects the plane
+	/// - **ray**: The ray to intersect the plane with
+	/// 
+	/// **Returns**: Returns the point of intersection, or `None` if the ray is parallel to the plane or pointing away from it
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Ray3, Plane};
+	/// let plane = Plane::xz_plane();
+	/// let point = plane.intersect_ray(Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::down()));
+	/// assert_eq!(Some(Vector3::new(1.0, 0.0, 2.0)), point);
+	/// let point = plane.intersect_ray(Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::up()));
+	/// assert_eq!(None, point);
+	/// let point = plane.intersect_ray(Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::right()));
+	/// assert_eq!(None, point);
+	/// ```
+	#[cfg(not(feature = "no_rays"))]
+	pub fn intersect_ray(&self, ray: Ray3) -> Option<Vector3> {
+		let diff = ray.direction().dot(self.normal);
+		
+		if Math::approx(diff, 0.0) { return None; }
+		
+		let distance = -(ray.origin().dot(self.normal) + self.distance) / diff;
+		
+		if distance < 0.0 { None }
+		else { Some(ray.get_point(distance)) }
+	}
+	
+	/// Gets the line where the two planes intersect
+	/// - **other**: The other plane to intersect with
+	/// 
+	/// **Returns**: Returns the line of intersection as a ray, or `None` if the planes are parallel
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Plane};
+	/// let a = Plane::xy_plane();
+	/// let b = Plane::xz_plane();
+	/// let ray = a.intersect_plane(b).unwrap();
+	/// assert_eq!(Vector3::zero(), ray.origin());
+	/// for distance in [-2.0, 0.0, 3.5] {
+	///     assert!(a.is_on_plane(ray.get_point(distance)));
+	///     assert!(b.is_on_plane(ray.get_point(distance)));
+	/// }
+	/// let a = Plane::new_from_point(Vector3::right(), Vector3::new(3.0, 0.0, 0.0));
+	/// let b = Plane::new(Vector3::new(0.0, 1.0, 1.0), -2.0);
+	/// let ray = a.intersect_plane(b).unwrap();
+	/// for distance in [-2.0, 0.0, 3.5] {
+	///     assert!(a.is_on_plane(ray.get_point(distance)));
+	///     assert!(b.is_on_plane(ray.get_point(distance)));
+	/// }
+	/// assert_eq!(None, a.intersect_plane(Plane::yz_plane()));
+	/// ```
+	#[cfg(not(feature = "no_rays"))]
+	pub fn intersect_plane(&self, other: Plane) -> Option<Ray3> {
+		let direction = self.normal.cross(other.normal);
+		let square_magnitude = direction.square_magnitude();
+		
+		if Math::approx(square_magnitude, 0.0) { return None; }
+		
+		let point = (other.distance * self.normal - self.distance * other.normal).cross(direction) / square_magnitude;
+		
+		Some(Ray3::new(point, direction.normalize()))
+	}
 }
 
-#[cfg(not(feature = "no_rays"))]
+#[cfg(not(any(feature = "no_rays", feature = "no_collision")))]
 impl IRaycast for Plane {
 	/// Raycasts with the given ray
 	/// - **ray**: The ray to raycast with
 	/// 
 	/// **Returns**: Returns the information on the raycast
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Ray3, Plane, interfaces::IRaycast};
+	/// let plane = Plane::xz_plane();
+	/// let info = plane.raycast(Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::down()));
+	/// assert!(info.is_hit());
+	/// assert_eq!(5.0, info.distance());
+	/// assert_eq!(Vector3::new(1.0, 0.0, 2.0), info.point());
+	/// assert_eq!(Vector3::up(), info.normal());
+	/// let info = plane.raycast(Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::up()));
+	/// assert!(!info.is_hit());
+	/// let info = plane.raycast(Ray3::new(Vector3::new(1.0, 5.0, 2.0), Vector3::right()));
+	/// assert!(!info.is_hit());
+	/// ```
 	fn raycast(&self, ray: Ray3) -> RaycastInfo {
 		let diff = ray.direction().dot(self.normal);
 		let dist = -(ray.origin().dot(self.normal) + self.distance);
@@ -298,5 +377,15 @@ impl std::fmt::Display for Plane {
 
 impl Neg for Plane {
 	type Output = Plane;
+	/// Flips the plane to the opposite direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3, Plane};
+	/// let plane = Plane::new(Vector3::up(), 2.0);
+	/// assert_eq!(plane.flipped(), -plane);
+	/// assert_eq!(Vector3::down(), (-plane).normal());
+	/// assert_eq!(-2.0, (-plane).distance());
+	/// assert_eq!(plane, -(-plane));
+	/// ```
 	fn neg(self) -> Self::Output { self.flipped() }
 }
@@ -1,9 +1,14 @@
 
+use core::hash::{Hash, Hasher};
 use core::ops::Neg;
 
-use crate::Math;
+use crate::{Exact, Math, ParseError, interfaces::ApproxEq, parse::parse_floats};
 #[cfg(not(feature = "no_vectors"))]
 use crate::{Vector2,Vector3};
+#[cfg(not(feature = "no_matrices"))]
+use crate::Matrix4x4;
+#[cfg(feature = "random")]
+use crate::Random;
 use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
 
 /// A 4D quaternion that holds 3 complex numbers and 1 real number
@@ -55,6 +60,19 @@ impl Quaternion {
 	/// ```
 	pub fn identity() -> Self { Quaternion::new(1.0, 0.0, 0.0, 0.0) }
 	
+	/// Creates a new quaternion from an array of its components
+	/// - **array**: The array holding the components in a, b, c, d order
+	/// 
+	/// **Returns**: Returns a new quaternion
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let quat = Quaternion::from_array([1.0, 2.0, 3.0, 4.0]);
+	/// assert_eq!(Quaternion::new(1.0, 2.0, 3.0, 4.0), quat);
+	/// assert_eq!(quat, Quaternion::from([1.0, 2.0, 3.0, 4.0]));
+	/// ```
+	pub fn from_array(array: [f32; 4]) -> Self { Quaternion::new(array[0], array[1], array[2], array[3]) }
+	
 	/// Creates a rotation quaternion over the given axis and angle in radians
 	/// - **axis**: The axis that the quaternion will rotate around
 	/// - **angle**: The angle in radians that the quaternion will rotate around
@@ -185,7 +203,102 @@ impl Quaternion {
 		));
 	}
 	
-	// TODO: Add a from_matrix function here
+	/// Creates a rotation quaternion from the rotation within the given matrix
+	/// - **matrix**: The matrix to get the rotation from, any translation within it is ignored
+	/// 
+	/// **Returns**: Returns the rotation quaternion from the given matrix
+	/// #### Remarks
+	/// Every rotation can be represented by two quaternions, `q` and `-q` (the double cover). So converting a quaternion
+	/// into a matrix and back may return the negated quaternion, which still represents the exact same rotation
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Matrix4x4};
+	/// assert_eq!(Quaternion::identity(), Quaternion::from_matrix(Matrix4x4::identity()));
+	/// let rotation = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// assert_eq!(rotation, Quaternion::from_matrix(rotation.to_matrix()));
+	/// let rotation = Quaternion::from_euler_deg(Vector3::new(-12.0, 40.0, 77.0));
+	/// assert_eq!(rotation, Quaternion::from_matrix(rotation.to_matrix()));
+	/// let rotation = Quaternion::from_axis_angle_deg(Vector3::right(), 180.0);
+	/// let actual = Quaternion::from_matrix(rotation.to_matrix());
+	/// assert!(actual == rotation || actual == -rotation);
+	/// # }
+	/// ```
+	#[cfg(not(feature = "no_matrices"))]
+	pub fn from_matrix(matrix: Matrix4x4) -> Self {
+		Quaternion::from_rotation_rows([
+			[matrix.get(0, 0), matrix.get(0, 1), matrix.get(0, 2)],
+			[matrix.get(1, 0), matrix.get(1, 1), matrix.get(1, 2)],
+			[matrix.get(2, 0), matrix.get(2, 1), matrix.get(2, 2)],
+		])
+	}
+	
+	/// Creates a rotation quaternion that looks along the forward direction, keeping the top facing the up direction
+	/// - **forward**: The direction to look towards
+	/// - **up**: The direction that the top should face towards, it gets re-orthogonalized against the forward direction
+	/// 
+	/// **Returns**: Returns the rotation quaternion that rotates `Vector3::forward()` onto the forward direction,
+	/// returns the identity quaternion if the forward direction is a zero vector
+	/// #### Remarks
+	/// If the forward and up directions are parallel then there's no single rotation that satisfies both,
+	/// so a secondary axis is used as the up direction instead
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3};
+	/// assert_eq!(Quaternion::identity(), Quaternion::look_rotation(Vector3::forward(), Vector3::up()));
+	/// let rotation = Quaternion::look_rotation(Vector3::right(), Vector3::up());
+	/// assert_eq!(Vector3::right(), rotation * Vector3::forward());
+	/// assert_eq!(Vector3::up(), rotation * Vector3::up());
+	/// let rotation = Quaternion::look_rotation(Vector3::new(0.0, 0.0, -2.0), Vector3::new(1.0, 1.0, 0.0));
+	/// assert_eq!(Vector3::back(), rotation * Vector3::forward());
+	/// assert_eq!(Vector3::new(1.0, 1.0, 0.0).normalize(), rotation * Vector3::up());
+	/// let rotation = Quaternion::look_rotation(Vector3::up(), Vector3::up());
+	/// assert_eq!(Vector3::up(), rotation * Vector3::forward());
+	/// assert_eq!(Quaternion::identity(), Quaternion::look_rotation(Vector3::zero(), Vector3::up()));
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn look_rotation(forward: Vector3, up: Vector3) -> Self {
+		if forward.square_magnitude() == 0.0 { return Quaternion::identity(); }
+		
+		let forward = forward.normalize();
+		let mut right = up.cross(forward);
+		
+		if right.square_magnitude() < 0.000001 { right = Vector3::back().cross(forward); }
+		if right.square_magnitude() < 0.000001 { right = Vector3::up().cross(forward); }
+		
+		let right = right.normalize();
+		let up = forward.cross(right);
+		
+		Quaternion::from_rotation_rows([
+			[right.x(), up.x(), forward.x()],
+			[right.y(), up.y(), forward.y()],
+			[right.z(), up.z(), forward.z()],
+		])
+	}
+	
+	/// Creates a random rotation, uniformly distributed over all rotations using Shoemake's method
+	/// - **random**: The random number generator to use
+	/// 
+	/// **Returns**: Returns a random normalized quaternion
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Random,Math,assert_range};
+	/// let mut random = Random::new(42);
+	/// for _ in 0..10 {
+	///     assert_range!(1.0, Quaternion::random(&mut random).magnitude());
+	/// }
+	/// assert_eq!(Quaternion::random(&mut Random::new(7)), Quaternion::random(&mut Random::new(7)));
+	/// ```
+	#[cfg(feature = "random")]
+	pub fn random(random: &mut Random) -> Self {
+		let u = random.next_f32();
+		let (sin1, cos1) = Math::sin_cos(random.range(0.0, Math::TWO_PI));
+		let (sin2, cos2) = Math::sin_cos(random.range(0.0, Math::TWO_PI));
+		let r1 = Math::sqrt(1.0 - u);
+		let r2 = Math::sqrt(u);
+		
+		Quaternion::new(r2 * cos2, r1 * sin1, r1 * cos1, r2 * sin2)
+	}
 }
 
 /// Properties
@@ -226,6 +339,29 @@ impl Quaternion {
 	/// - **value**: The value to set the complex *k* component of the quaternion
 	pub fn set_d(&mut self, value: f32) { self.d = value; }
 	
+	/// Gets the real part of the quaternion, which is the *a* component
+	/// 
+	/// **Returns**: Returns the real part of the quaternion
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let quat = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+	/// assert_eq!(1.0, quat.scalar_part());
+	/// ```
+	pub fn scalar_part(&self) -> f32 { self.a }
+	
+	/// Gets the complex part of the quaternion as a vector made of the *b*, *c*, and *d* components
+	/// 
+	/// **Returns**: Returns the complex part of the quaternion as a 3D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3};
+	/// let quat = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+	/// assert_eq!(Vector3::new(2.0, 3.0, 4.0), quat.vector_part());
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn vector_part(&self) -> Vector3 { Vector3::new(self.b, self.c, self.d) }
+	
 	/// Gets the euler angles (in radians) of the quaternion
 	/// 
 	/// **Returns**: Returns the euler angles (in radians) in a 3D vector
@@ -358,6 +494,45 @@ impl Quaternion {
 		self.d = quat.d;
 	}
 	
+	/// Gets the forward direction of the rotation, rotating `Vector3::forward()` by the quaternion
+	/// 
+	/// **Returns**: Returns the rotated forward direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3};
+	/// assert_eq!(Vector3::forward(), Quaternion::identity().forward());
+	/// let quat = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// assert_eq!(Vector3::right(), quat.forward());
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn forward(&self) -> Vector3 { self.multiply_vector3(Vector3::forward()) }
+	
+	/// Gets the right direction of the rotation, rotating `Vector3::right()` by the quaternion
+	/// 
+	/// **Returns**: Returns the rotated right direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3};
+	/// assert_eq!(Vector3::right(), Quaternion::identity().right());
+	/// let quat = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// assert_eq!(Vector3::back(), quat.right());
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn right(&self) -> Vector3 { self.multiply_vector3(Vector3::right()) }
+	
+	/// Gets the up direction of the rotation, rotating `Vector3::up()` by the quaternion
+	/// 
+	/// **Returns**: Returns the rotated up direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3};
+	/// assert_eq!(Vector3::up(), Quaternion::identity().up());
+	/// let quat = Quaternion::from_axis_angle_deg(Vector3::right(), 90.0);
+	/// assert_eq!(Vector3::forward(), quat.up());
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn up(&self) -> Vector3 { self.multiply_vector3(Vector3::up()) }
+	
 	/// Gets the magnitude of the quaternion
 	/// 
 	/// **Returns**: Returns the magnitude of the quaternion
@@ -404,7 +579,132 @@ impl Quaternion {
 
 /// Public Methods
 impl Quaternion {
-	// TODO: to_matrix
+	/// Converts the quaternion into a rotation matrix
+	/// 
+	/// **Returns**: Returns a 4x4 matrix that rotates the same way as the quaternion, with no translation
+	/// #### Remarks
+	/// The quaternion is normalized before being converted, so a non-normalized quaternion still produces a pure rotation
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Matrix4x4,Math,assert_range};
+	/// let rotation = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// let matrix = rotation.to_matrix();
+	/// assert_eq!(rotation * Vector3::right(), matrix * Vector3::right());
+	/// assert_eq!(Vector3::new(0.0, 0.0, -1.0), matrix * Vector3::right());
+	/// let vector = Vector3::new(100.0, 200.0, 300.0);
+	/// let rotation = Quaternion::from_euler_deg(Vector3::new(-12.0, 40.0, 77.0));
+	/// let expected = rotation * vector;
+	/// let actual = rotation.to_matrix() * vector;
+	/// assert_range!(expected.x(), actual.x(), 0.001);
+	/// assert_range!(expected.y(), actual.y(), 0.001);
+	/// assert_range!(expected.z(), actual.z(), 0.001);
+	/// assert_eq!(rotation.to_matrix(), (2.0 * rotation).to_matrix());
+	/// assert_eq!(Matrix4x4::identity(), Quaternion::identity().to_matrix());
+	/// # }
+	/// ```
+	#[cfg(not(feature = "no_matrices"))]
+	pub fn to_matrix(self) -> Matrix4x4 {
+		let quat = self.normalize();
+		let (w, x, y, z) = (quat.a, quat.b, quat.c, quat.d);
+		
+		Matrix4x4::new([
+			1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z), 2.0 * (x * z + w * y), 0.0,
+			2.0 * (x * y + w * z), 1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x), 0.0,
+			2.0 * (x * z - w * y), 2.0 * (y * z + w * x), 1.0 - 2.0 * (x * x + y * y), 0.0,
+			0.0, 0.0, 0.0, 1.0,
+		])
+	}
+	
+	/// Gets the angle in radians that the quaternion rotates by
+	/// 
+	/// **Returns**: Returns the angle of the rotation in radians, between 0.0 and π
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let rotation = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_2);
+	/// assert_range!(Math::PI_OVER_2, rotation.angle(), 0.001);
+	/// assert_range!(Math::PI_OVER_2, (-rotation).angle(), 0.001);
+	/// assert_range!(Math::PI_OVER_2, (2.0 * rotation).angle(), 0.001);
+	/// assert_range!(0.0, Quaternion::identity().angle());
+	/// # }
+	/// ```
+	pub fn angle(self) -> f32 {
+		let real = Math::abs(self.normalize().a);
+		
+		2.0 * Math::acos(Math::clamp(real, 0.0, 1.0))
+	}
+	
+	/// Gets the angle in radians between the two rotations
+	/// - **rhs**: The other rotation to get the angle from
+	/// 
+	/// **Returns**: Returns the angle in radians of the rotation needed to go from one rotation to the other,
+	/// between 0.0 and π
+	/// #### Remarks
+	/// Since `q` and `-q` represent the same rotation (the double cover), the angle between them is 0.0
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let a = Quaternion::from_axis_angle(Vector3::up(), Math::PI_OVER_4);
+	/// let b = Quaternion::from_axis_angle(Vector3::up(), -Math::PI_OVER_4);
+	/// assert_range!(Math::PI_OVER_2, a.angle_between(b), 0.001);
+	/// assert_range!(Math::PI_OVER_4, a.angle_between(Quaternion::identity()), 0.001);
+	/// assert_range!(0.0, a.angle_between(a), 0.001);
+	/// assert_range!(0.0, a.angle_between(-a), 0.001);
+	/// # }
+	/// ```
+	pub fn angle_between(self, rhs: Quaternion) -> f32 {
+		let dot = Math::abs(self.normalize().dot(rhs.normalize()));
+		
+		2.0 * Math::acos(Math::clamp(dot, 0.0, 1.0))
+	}
+	
+	/// Gets the angle in degrees between the two rotations
+	/// - **rhs**: The other rotation to get the angle from
+	/// 
+	/// **Returns**: Returns the angle in degrees of the rotation needed to go from one rotation to the other,
+	/// between 0.0 and 180.0
+	/// #### Remarks
+	/// Since `q` and `-q` represent the same rotation (the double cover), the angle between them is 0.0
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let a = Quaternion::from_axis_angle_deg(Vector3::up(), 45.0);
+	/// let b = Quaternion::from_axis_angle_deg(Vector3::up(), -45.0);
+	/// assert_range!(90.0, a.angle_between_deg(b), 0.05);
+	/// assert_range!(0.0, a.angle_between_deg(-a), 0.05);
+	/// # }
+	/// ```
+	pub fn angle_between_deg(self, rhs: Quaternion) -> f32 { Math::rad2deg(self.angle_between(rhs)) }
+	
+	/// Approximately compares this quaternion with the expected quaternion, describing the difference when they diverge
+	/// - **expected**: The quaternion that this quaternion is expected to be
+	/// - **epsilon**: The epsilon (smallest possible difference between numbers) to check with
+	/// 
+	/// **Returns**: Returns `Ok` if every component is approximately equal, otherwise returns an `Err` naming
+	/// the first component that diverges and by how much
+	/// #### Remarks
+	/// This allocates the error message and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let quat = Quaternion::new(1.0, 0.0, 0.5, 0.0);
+	/// assert_eq!(Ok(()), quat.assert_approx(&Quaternion::new(1.0, 0.0, 0.5001, 0.0), 0.001));
+	/// let error = quat.assert_approx(&Quaternion::identity(), 0.001).unwrap_err();
+	/// assert_eq!("c differs by 0.5 (expected 0, actual 0.5)", error);
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn assert_approx(&self, expected: &Quaternion, epsilon: f32) -> Result<(), String> {
+		Math::approx_components(&[
+			("a", expected.a, self.a),
+			("b", expected.b, self.b),
+			("c", expected.c, self.c),
+			("d", expected.d, self.d),
+		], epsilon)
+	}
 	
 	/// Conjugates the quaternion, so it turns it from (a + b *i* + c *j* + d *k*) to (a - b *i* - c *j* - d *k*)
 	/// 
@@ -418,6 +718,27 @@ impl Quaternion {
 	/// ```
 	pub fn conjugate(self) -> Self { Quaternion::new(self.a, -self.b, -self.c, -self.d) }
 	
+	/// Gets the rotation that takes this rotation to the other rotation
+	/// - **other**: The rotation to end up at
+	/// 
+	/// **Returns**: Returns the relative rotation `other * self.invert()`, which gives the other rotation when multiplied with this rotation
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3};
+	/// let a = Quaternion::from_euler_deg(Vector3::new(10.0, 20.0, 30.0));
+	/// let b = Quaternion::from_euler_deg(Vector3::new(-45.0, 90.0, 5.0));
+	/// let difference = a.difference(b);
+	/// assert_eq!(b, difference * a);
+	/// assert_eq!(a, b.difference(a) * b);
+	/// assert_eq!(Quaternion::identity(), a.difference(a));
+	/// let a = Quaternion::from_axis_angle_deg(Vector3::up(), 30.0);
+	/// let b = Quaternion::from_axis_angle_deg(Vector3::up(), 75.0);
+	/// assert_eq!(Quaternion::from_axis_angle_deg(Vector3::up(), 45.0), a.difference(b));
+	/// # }
+	/// ```
+	pub fn difference(self, other: Quaternion) -> Self { other * self.invert() }
+	
 	/// Divides the two quaternions together
 	/// - **rhs**: The other quaternion to divide with
 	/// 
@@ -453,6 +774,24 @@ impl Quaternion {
 	/// ```
 	pub fn dot(self, rhs: Quaternion) -> f32 { self.a * rhs.a + self.b * rhs.b + self.c * rhs.c + self.d * rhs.d }
 	
+	/// Dot products the two quaternions together using fused multiply-adds
+	/// - **rhs**: The other quaternion to get the dot product with
+	/// 
+	/// **Returns**: Returns the dot product
+	/// #### Remarks
+	/// This rounds fewer times than [`Quaternion::dot`] when using the standard library. With `no_std` it falls back
+	/// to separate multiplies and adds, so results may differ slightly between the two
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+	/// let b = Quaternion::new(5.0, 6.0, 7.0, 8.0);
+	/// assert_eq!(70.0, a.dot_fma(b));
+	/// ```
+	pub fn dot_fma(self, rhs: Quaternion) -> f32 {
+		Math::fma(self.a, rhs.a, Math::fma(self.b, rhs.b, Math::fma(self.c, rhs.c, self.d * rhs.d)))
+	}
+	
 	/// Inverts the quaternion
 	/// 
 	/// **Returns**: Returns the inverted quaternion
@@ -473,6 +812,75 @@ impl Quaternion {
 		return self.conjugate() / magnitude;
 	}
 	
+	/// Finds if all the components of the quaternion are finite, being neither NaN nor infinite
+	/// 
+	/// **Returns**: Returns true if all the components are finite
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let quat = Quaternion::new(0.5, -0.5, 0.5, 0.5);
+	/// assert!(quat.is_finite());
+	/// let quat = Quaternion::new(0.5, f32::NAN, 0.5, 0.5);
+	/// assert!(!quat.is_finite());
+	/// let quat = Quaternion::new(f32::INFINITY, 0.0, 0.0, 0.0);
+	/// assert!(!quat.is_finite());
+	/// ```
+	pub fn is_finite(&self) -> bool { self.to_array().iter().all(|value| value.is_finite()) }
+	
+	/// Linearly interpolates between the two quaternions then normalizes the result (nlerp)
+	/// - **rhs**: The other quaternion to interpolate towards
+	/// - **t**: The ratio value to interpolate between both quaternions. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated quaternion
+	/// #### Remarks
+	/// This is cheaper than `slerp` and is usually good enough for small rotations, but unlike `slerp`
+	/// it does not rotate with a constant angular velocity
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Math,assert_range};
+	/// let a = Quaternion::new(0.8660254, 0.0, 0.5, 0.0);
+	/// let b = Quaternion::new(0.4158418, 0.1114245, -0.2336062, 0.8718304);
+	/// assert_eq!(a, a.lerp(b, 0.0));
+	/// assert_eq!(b, a.lerp(b, 1.0));
+	/// assert_eq!(b, a.lerp(b, 1.5));
+	/// for t in [0.0, 1.0] {
+	///     let (lerped, slerped) = (a.lerp(b, t), a.slerp(b, t));
+	///     assert_range!(slerped.a(), lerped.a(), 0.001);
+	///     assert_range!(slerped.b(), lerped.b(), 0.001);
+	///     assert_range!(slerped.c(), lerped.c(), 0.001);
+	///     assert_range!(slerped.d(), lerped.d(), 0.001);
+	/// }
+	/// let expected = Quaternion::new(0.81289685, 0.07065991, 0.1689338, 0.55287176);
+	/// assert_range!(expected.a(), a.lerp(b, 0.5).a(), 0.001);
+	/// assert_range!(expected.b(), a.lerp(b, 0.5).b(), 0.001);
+	/// assert_range!(expected.c(), a.lerp(b, 0.5).c(), 0.001);
+	/// assert_range!(expected.d(), a.lerp(b, 0.5).d(), 0.001);
+	/// ```
+	pub fn lerp(self, rhs: Quaternion, t: f32) -> Self { self.lerp_unclamped(rhs, Math::clamp(t, 0.0, 1.0)) }
+	
+	/// Linearly interpolates between the two quaternions then normalizes the result (not clamped)
+	/// - **rhs**: The other quaternion to interpolate towards
+	/// - **t**: The ratio value to interpolate between both quaternions
+	/// 
+	/// **Returns**: Returns the interpolated quaternion
+	/// #### Remarks
+	/// If the quaternions are more than 90 degrees apart within 4D space, then the other quaternion is negated
+	/// (which still represents the same rotation) so the shortest path is taken
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let a = Quaternion::identity();
+	/// let b = Quaternion::new(0.70710678, 0.70710678, 0.0, 0.0);
+	/// let expected = Quaternion::new(0.9238795, 0.38268343, 0.0, 0.0);
+	/// assert_eq!(expected, a.lerp_unclamped(b, 0.5));
+	/// assert_eq!(expected, a.lerp_unclamped(-b, 0.5));
+	/// ```
+	pub fn lerp_unclamped(self, rhs: Quaternion, t: f32) -> Self {
+		let rhs = if self.dot(rhs) < 0.0 { -rhs } else { rhs };
+		
+		(self + t * (rhs - self)).normalize()
+	}
+	
 	/// Multiplies the two quaternions together
 	/// - **rhs**: The other quaternion to multiply with
 	/// 
@@ -554,6 +962,86 @@ impl Quaternion {
 	/// ```
 	pub fn normalize(self) -> Self { self / self.magnitude() }
 	
+	/// Raises the rotation to the given power, scaling the angle of the rotation while keeping it's axis
+	/// - **exponent**: The exponent to raise the rotation by, where 0.5 is half of the rotation and 2.0 is double the rotation
+	/// 
+	/// **Returns**: Returns the scaled rotation
+	/// #### Remarks
+	/// The quaternion is normalized first. If the rotation is approximately the identity there is no axis
+	/// to rotate around, so the identity is returned
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let rotation = Quaternion::from_axis_angle_deg(Vector3::new(1.0, 2.0, 3.0), 90.0);
+	/// let half = rotation.pow(0.5);
+	/// assert_range!(Math::PI_OVER_4, half.angle(), 0.001);
+	/// for (expected, actual) in [
+	///     (rotation, half * half),
+	///     (rotation, rotation.pow(1.0)),
+	///     (rotation * rotation, rotation.pow(2.0)),
+	///     (rotation.invert(), rotation.pow(-1.0)),
+	///     (Quaternion::identity(), rotation.pow(0.0)),
+	/// ] {
+	///     for (e, a) in expected.to_array().into_iter().zip(actual.to_array()) {
+	///         assert_range!(e, a, 0.0001);
+	///     }
+	/// }
+	/// assert_eq!(Quaternion::identity(), Quaternion::identity().pow(0.5));
+	/// # }
+	/// ```
+	pub fn pow(self, exponent: f32) -> Self {
+		let unit = self.normalize();
+		let sin = Math::sqrt(unit.b * unit.b + unit.c * unit.c + unit.d * unit.d);
+		
+		if Math::approx_zero(sin) { return Quaternion::identity(); }
+		
+		let angle = Math::atan2(sin, unit.a);
+		let (new_sin, new_cos) = Math::sin_cos(exponent * angle);
+		let scale = new_sin / sin;
+		
+		Quaternion::new(new_cos, scale * unit.b, scale * unit.c, scale * unit.d)
+	}
+	
+	/// Rotates this quaternion towards the target quaternion, never rotating past the target
+	/// - **target**: The quaternion to rotate towards
+	/// - **max_degrees_delta**: The maximum angle in degrees to rotate by
+	/// 
+	/// **Returns**: Returns the rotated quaternion, or the target if it's within the maximum angle
+	/// #### Examples
+	/// ```
+	/// # #[cfg(not(feature = "no_vectors"))] {
+	/// # use mathx::{Quaternion,Vector3,Math,assert_range};
+	/// let a = Quaternion::identity();
+	/// let b = Quaternion::from_axis_angle_deg(Vector3::up(), 90.0);
+	/// let rotated = a.rotate_towards(b, 30.0);
+	/// assert_range!(30.0, a.angle_between_deg(rotated), 0.01);
+	/// assert_range!(60.0, rotated.angle_between_deg(b), 0.01);
+	/// assert_eq!(b, a.rotate_towards(b, 120.0));
+	/// # }
+	/// ```
+	pub fn rotate_towards(self, target: Quaternion, max_degrees_delta: f32) -> Self {
+		let angle = self.angle_between_deg(target);
+		
+		if angle <= max_degrees_delta || angle == 0.0 { target }
+		else { self.slerp_unclamped(target, max_degrees_delta / angle) }
+	}
+	
+	/// Rotates the vector by the quaternion, the same as `multiply_vector3`
+	/// - **vector**: The vector to rotate
+	/// 
+	/// **Returns**: Returns the rotated vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Quaternion,Vector3};
+	/// let quat = Quaternion::from_axis_angle_deg(Vector3::forward(), 90.0);
+	/// let vector = Vector3::new(1.0, 0.0, 2.0);
+	/// assert_eq!(Vector3::new(0.0, 1.0, 2.0), quat.rotate_vector(vector));
+	/// assert_eq!(quat * vector, quat.rotate_vector(vector));
+	/// ```
+	#[cfg(not(feature = "no_vectors"))]
+	pub fn rotate_vector(self, vector: Vector3) -> Vector3 { self.multiply_vector3(vector) }
+	
 	/// Spherically interpolates between the two quaternions
 	/// - **rhs**: The other quaternion to interpolate towards
 	/// - **t**: The clamped ratio (t) to interpolate with
@@ -611,20 +1099,145 @@ impl Quaternion {
 	
 }
 
+/// Private Methods
+impl Quaternion {
+	/// Creates a rotation quaternion from the rows of a 3x3 rotation matrix, using the trace when it's positive
+	/// and otherwise the largest diagonal element for numerical stability
+	/// - **rows**: The rows of the rotation matrix
+	/// 
+	/// **Returns**: Returns the rotation quaternion from the rotation matrix
+	#[cfg(not(all(feature = "no_vectors", feature = "no_matrices")))]
+	fn from_rotation_rows(rows: [[f32; 3]; 3]) -> Self {
+		let m = |row: usize, col: usize| rows[row][col];
+		let trace = m(0, 0) + m(1, 1) + m(2, 2);
+		
+		if trace > 0.0 {
+			let s = 2.0 * Math::sqrt(trace + 1.0);
+			
+			Quaternion::new(
+				0.25 * s,
+				(m(2, 1) - m(1, 2)) / s,
+				(m(0, 2) - m(2, 0)) / s,
+				(m(1, 0) - m(0, 1)) / s
+			)
+		}
+		else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+			let s = 2.0 * Math::sqrt(1.0 + m(0, 0) - m(1, 1) - m(2, 2));
+			
+			Quaternion::new(
+				(m(2, 1) - m(1, 2)) / s,
+				0.25 * s,
+				(m(0, 1) + m(1, 0)) / s,
+				(m(0, 2) + m(2, 0)) / s
+			)
+		}
+		else if m(1, 1) > m(2, 2) {
+			let s = 2.0 * Math::sqrt(1.0 + m(1, 1) - m(0, 0) - m(2, 2));
+			
+			Quaternion::new(
+				(m(0, 2) - m(2, 0)) / s,
+				(m(0, 1) + m(1, 0)) / s,
+				0.25 * s,
+				(m(1, 2) + m(2, 1)) / s
+			)
+		}
+		else {
+			let s = 2.0 * Math::sqrt(1.0 + m(2, 2) - m(0, 0) - m(1, 1));
+			
+			Quaternion::new(
+				(m(1, 0) - m(0, 1)) / s,
+				(m(0, 2) + m(2, 0)) / s,
+				(m(1, 2) + m(2, 1)) / s,
+				0.25 * s
+			)
+		}
+	}
+}
+
+/// Conversions
+impl Quaternion {
+	/// Converts the quaternion into an array of its components
+	/// 
+	/// **Returns**: Returns the array holding the components in a, b, c, d order
+	/// #### Examples
+	/// ```
+	/// # use mathx::Quaternion;
+	/// let quat = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+	/// assert_eq!([1.0, 2.0, 3.0, 4.0], quat.to_array());
+	/// let array: [f32; 4] = quat.into();
+	/// assert_eq!(quat, Quaternion::from_array(array));
+	/// ```
+	pub fn to_array(self) -> [f32; 4] { [self.a, self.b, self.c, self.d] }
+}
+
+impl From<[f32; 4]> for Quaternion {
+	fn from(value: [f32; 4]) -> Self { Quaternion::from_array(value) }
+}
+
+impl From<Quaternion> for [f32; 4] {
+	fn from(value: Quaternion) -> Self { value.to_array() }
+}
+
 unsafe impl Send for Quaternion {}
 unsafe impl Sync for Quaternion {}
 
+// Default
+/// Gets the identity quaternion, the same as `Quaternion::identity()`
+/// #### Examples
+/// ```
+/// # use mathx::Quaternion;
+/// assert_eq!(Quaternion::identity(), Quaternion::default());
+/// ```
+impl Default for Quaternion {
+	fn default() -> Self { Quaternion::identity() }
+}
+
 // Equates
 impl Eq for Quaternion {}
 impl PartialEq for Quaternion {
-	fn eq(&self, other: &Self) -> bool {
-		Math::approx(self.a, other.a)
-		&& Math::approx(self.b, other.b)
-		&& Math::approx(self.c, other.c)
-		&& Math::approx(self.d, other.d)
+	fn eq(&self, other: &Self) -> bool { self.approx_eq(other) }
+}
+
+/// Approximately compares each component of the quaternion
+/// #### Examples
+/// ```
+/// # use mathx::Quaternion;
+/// # use mathx::interfaces::ApproxEq;
+/// let a = Quaternion::new(1.0, 2.0, 3.0, 4.0);
+/// let b = Quaternion::new(1.0, 2.0, 3.0001, 4.0);
+/// assert!(a.approx_eq_eps(&b, 0.001));
+/// assert!(!a.approx_eq(&b));
+/// ```
+impl ApproxEq for Quaternion {
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool {
+		Math::approx_epsilon(self.a, other.a, epsilon)
+		&& Math::approx_epsilon(self.b, other.b, epsilon)
+		&& Math::approx_epsilon(self.c, other.c, epsilon)
+		&& Math::approx_epsilon(self.d, other.d, epsilon)
 	}
 }
 
+// Hashing
+/// Hashes the exact bits of each component, allowing the quaternion to be used as a key
+/// #### Examples
+/// ```
+/// # use std::collections::HashMap;
+/// # use mathx::{Exact,Quaternion};
+/// let mut map = HashMap::new();
+/// map.insert(Exact(Quaternion::new(1.0, 2.0, 3.0, 4.0)), 10);
+/// assert_eq!(Some(&10), map.get(&Exact(Quaternion::new(1.0, 2.0, 3.0, 4.0))));
+/// assert_eq!(Quaternion::new(-0.0, 2.0, 3.0, 4.0), Quaternion::new(0.0, 2.0, 3.0, 4.0));
+/// assert_ne!(Exact(Quaternion::new(-0.0, 2.0, 3.0, 4.0)), Exact(Quaternion::new(0.0, 2.0, 3.0, 4.0)));
+/// ```
+impl Hash for Exact<Quaternion> {
+	fn hash<H: Hasher>(&self, state: &mut H) { self.0.to_array().map(f32::to_bits).hash(state); }
+}
+
+impl Eq for Exact<Quaternion> {}
+impl PartialEq for Exact<Quaternion> {
+	fn eq(&self, other: &Self) -> bool { self.0.to_array().map(f32::to_bits) == other.0.to_array().map(f32::to_bits) }
+}
+
 // Display
 #[cfg(not(feature = "no_std"))]
 impl std::fmt::Display for Quaternion {
@@ -633,6 +1246,35 @@ impl std::fmt::Display for Quaternion {
 	}
 }
 
+// Parsing
+/// Parses the quaternion from four comma-separated numbers that can be surrounded by parentheses,
+/// where the complex components can optionally end with their `i`, `j` and `k` units, such as `(1, 2i, 3j, 4k)`
+/// #### Remarks
+/// This accepts the same format as the `Display` output, so `quat.to_string().parse()` gives back the same quaternion
+/// #### Examples
+/// ```
+/// # use mathx::{Quaternion,ParseError};
+/// let quat: Quaternion = "(1, 0.5i, -2j, 3k)".parse().unwrap();
+/// assert_eq!(Quaternion::new(1.0, 0.5, -2.0, 3.0), quat);
+/// let quat: Quaternion = "1, 0.5, -2, 3".parse().unwrap();
+/// assert_eq!(Quaternion::new(1.0, 0.5, -2.0, 3.0), quat);
+/// # #[cfg(not(feature = "no_std"))] {
+/// let quat = Quaternion::new(0.8660254, 0.0, -0.5, 1e-4);
+/// assert_eq!(Ok(quat), quat.to_string().parse());
+/// # }
+/// assert_eq!(Err(ParseError::WrongCount { expected: 4, found: 3 }), "1, 2i, 3j".parse::<Quaternion>());
+/// assert_eq!(Err(ParseError::InvalidNumber { index: 2 }), "1, 2i, 3x, 4k".parse::<Quaternion>());
+/// ```
+impl core::str::FromStr for Quaternion {
+	type Err = ParseError;
+	
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let values = parse_floats(text, ["", "i", "j", "k"])?;
+		
+		Ok(Quaternion::from_array(values))
+	}
+}
+
 impl AddSubArithmetic<Quaternion> for Quaternion {
 	type Output = Quaternion;
 	fn add_other(self, rhs: Quaternion) -> Self::Output {
@@ -0,0 +1,129 @@
+
+/// A small deterministic pseudo-random number generator using the PCG32 algorithm.
+/// The same seed will always produce the same sequence of numbers, making it useful for procedural generation
+#[derive(Debug, Clone)]
+pub struct Random {
+	/// The current state of the generator
+	state: u64,
+	/// The odd increment that selects the stream of the generator
+	increment: u64,
+}
+
+/// Constructors
+impl Random {
+	/// Creates a new random number generator from the given seed
+	/// - **seed**: The seed to start the generator with
+	/// 
+	/// **Returns**: Returns a new random number generator
+	/// #### Examples
+	/// ```
+	/// # use mathx::Random;
+	/// let mut a = Random::new(42);
+	/// let mut b = Random::new(42);
+	/// for _ in 0..10 {
+	///     assert_eq!(a.next_u32(), b.next_u32());
+	/// }
+	/// let mut c = Random::new(7);
+	/// assert_ne!(Random::new(42).next_u32(), c.next_u32());
+	/// ```
+	pub fn new(seed: u64) -> Self {
+		let mut random = Random { state: 0, increment: (0xda3e_39cb_94b9_5bdb << 1) | 1 };
+		
+		random.step();
+		random.state = random.state.wrapping_add(seed);
+		random.step();
+		random
+	}
+}
+
+/// Public Methods
+impl Random {
+	/// Gets the next random floating point number
+	/// 
+	/// **Returns**: Returns a random number between 0.0 (inclusive) and 1.0 (exclusive)
+	/// #### Examples
+	/// ```
+	/// # use mathx::Random;
+	/// let mut random = Random::new(42);
+	/// for _ in 0..100 {
+	///     let value = random.next_f32();
+	///     assert!(value >= 0.0 && value < 1.0);
+	/// }
+	/// ```
+	pub fn next_f32(&mut self) -> f32 { (self.next_u32() >> 8) as f32 / 16777216.0 }
+	
+	/// Gets the next random integer
+	/// 
+	/// **Returns**: Returns a random integer that can be any `u32`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Random;
+	/// let mut a = Random::new(1234);
+	/// let first = [a.next_u32(), a.next_u32(), a.next_u32()];
+	/// let mut b = Random::new(1234);
+	/// assert_eq!(first, [b.next_u32(), b.next_u32(), b.next_u32()]);
+	/// ```
+	pub fn next_u32(&mut self) -> u32 {
+		let old = self.state;
+		
+		self.step();
+		
+		let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+		let rotation = (old >> 59) as u32;
+		
+		xorshifted.rotate_right(rotation)
+	}
+	
+	/// Gets the next random floating point number within the range
+	/// - **min**: The lowest the number can be (inclusive)
+	/// - **max**: The highest the number can be (exclusive)
+	/// 
+	/// **Returns**: Returns a random number between the min and max
+	/// #### Examples
+	/// ```
+	/// # use mathx::Random;
+	/// let mut random = Random::new(42);
+	/// for _ in 0..100 {
+	///     let value = random.range(-5.0, 5.0);
+	///     assert!(value >= -5.0 && value < 5.0);
+	/// }
+	/// ```
+	pub fn range(&mut self, min: f32, max: f32) -> f32 { min + (max - min) * self.next_f32() }
+	
+	/// Gets the next random integer within the range
+	/// - **min**: The lowest the integer can be (inclusive)
+	/// - **max**: The highest the integer can be (exclusive)
+	/// 
+	/// **Returns**: Returns a random integer between the min and max, returns the min if the max is not greater than the min
+	/// #### Examples
+	/// ```
+	/// # use mathx::Random;
+	/// let mut random = Random::new(42);
+	/// for _ in 0..100 {
+	///     let value = random.range_i32(-3, 3);
+	///     assert!(value >= -3 && value < 3);
+	/// }
+	/// assert_eq!(5, random.range_i32(5, 5));
+	/// assert_eq!(i32::MIN, random.range_i32(i32::MIN, i32::MIN + 1));
+	/// ```
+	pub fn range_i32(&mut self, min: i32, max: i32) -> i32 {
+		if max <= min { return min; }
+		
+		let span = (max as i64 - min as i64) as u64;
+		
+		(min as i64 + ((self.next_u32() as u64 * span) >> 32) as i64) as i32
+	}
+}
+
+/// Private Methods
+impl Random {
+	/// Advances the state of the generator by one step
+	fn step(&mut self) {
+		self.state = self.state
+			.wrapping_mul(6364136223846793005)
+			.wrapping_add(self.increment);
+	}
+}
+
+unsafe impl Send for Random {}
+unsafe impl Sync for Random {}
@@ -2,6 +2,7 @@
 use core::ops::{Neg, Mul, MulAssign, Div, DivAssign};
 
 use crate::Ray3;
+use crate::Math;
 use crate::Vector2;
 use crate::{MulDivScalar, impl_mul, impl_div};
 
@@ -132,9 +133,44 @@ impl Ray2 {
 	/// assert_eq!(2.0, distance);
 	/// ```
 	pub fn distance(self, point: Vector2) -> f32 { point.distance(self.closest_point(point)) }
+	
+	/// Marches along the ray at fixed intervals, yielding each point along the way
+	/// - **step**: The distance between each point
+	/// - **max_distance**: The distance along the ray to stop marching at
+	/// 
+	/// **Returns**: Returns an iterator of the points at the distances `0, step, 2 * step, ...` up to and including `max_distance`.
+	/// Nothing is yielded if the step is not positive or the max distance is negative
+	/// #### Remarks
+	/// Distances are measured in units of the ray's direction, so the spacing between points equals the step only when the
+	/// direction is a unit vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray2, Vector2};
+	/// let ray = Ray2::new(Vector2::zero(), Vector2::right());
+	/// let points: Vec<Vector2> = ray.march(0.25, 1.0).collect();
+	/// assert_eq!(5, points.len());
+	/// assert_eq!(Vector2::new(0.0, 0.0), points[0]);
+	/// assert_eq!(Vector2::new(1.0, 0.0), points[4]);
+	/// assert!(points.windows(2).all(|pair| pair[0].distance(pair[1]) == 0.25));
+	/// assert_eq!(0, ray.march(0.25, -1.0).count());
+	/// ```
+	pub fn march(self, step: f32, max_distance: f32) -> impl Iterator<Item = Vector2> {
+		let count = if step > 0.0 && max_distance >= 0.0 { Math::floor(max_distance / step) as usize + 1 } else { 0 };
+		
+		(0..count).map(move |i| self.get_point(i as f32 * step))
+	}
 }
 
 impl From<Ray3> for Ray2 {
+	/// Converts the 3D ray into a 2D ray by dropping the z coordinate of both the origin and direction
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray2,Ray3,Vector2,Vector3};
+	/// let ray = Ray2::from(Ray3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 1.0, 1.0)));
+	/// assert_eq!(Ray2::new(Vector2::new(1.0, 2.0), Vector2::up()), ray);
+	/// let ray: Ray3 = ray.into();
+	/// assert_eq!(Ray3::new(Vector3::new(1.0, 2.0, 0.0), Vector3::up()), ray);
+	/// ```
 	fn from(value: Ray3) -> Self {
 		Ray2::new(value.origin().to_vector2(), value.direction().to_vector2())
 	}
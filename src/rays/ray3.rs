@@ -2,6 +2,7 @@
 use core::ops::{Neg, Mul, MulAssign, Div, DivAssign};
 
 use crate::Ray2;
+use crate::Math;
 use crate::Vector3;
 use crate::{MulDivScalar, impl_mul, impl_div};
 
@@ -132,9 +133,44 @@ impl Ray3 {
 	/// assert_eq!(2.236068, distance);
 	/// ```
 	pub fn distance(self, point: Vector3) -> f32 { point.distance(self.closest_point(point)) }
+	
+	/// Marches along the ray at fixed intervals, yielding each point along the way
+	/// - **step**: The distance between each point
+	/// - **max_distance**: The distance along the ray to stop marching at
+	/// 
+	/// **Returns**: Returns an iterator of the points at the distances `0, step, 2 * step, ...` up to and including `max_distance`.
+	/// Nothing is yielded if the step is not positive or the max distance is negative
+	/// #### Remarks
+	/// Distances are measured in units of the ray's direction, so the spacing between points equals the step only when the
+	/// direction is a unit vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray3, Vector3};
+	/// let ray = Ray3::new(Vector3::one(), Vector3::forward());
+	/// let points: Vec<Vector3> = ray.march(0.5, 1.5).collect();
+	/// assert_eq!(4, points.len());
+	/// assert_eq!(Vector3::new(1.0, 1.0, 1.0), points[0]);
+	/// assert_eq!(Vector3::new(1.0, 1.0, 2.5), points[3]);
+	/// assert!(points.windows(2).all(|pair| pair[0].distance(pair[1]) == 0.5));
+	/// assert_eq!(0, ray.march(0.0, 1.5).count());
+	/// ```
+	pub fn march(self, step: f32, max_distance: f32) -> impl Iterator<Item = Vector3> {
+		let count = if step > 0.0 && max_distance >= 0.0 { Math::floor(max_distance / step) as usize + 1 } else { 0 };
+		
+		(0..count).map(move |i| self.get_point(i as f32 * step))
+	}
 }
 
 impl From<Ray2> for Ray3 {
+	/// Converts the 2D ray into a 3D ray that lies on the z = 0 plane
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Ray2,Ray3,Vector2,Vector3};
+	/// let ray = Ray3::from(Ray2::new(Vector2::new(1.0, 2.0), Vector2::up()));
+	/// assert_eq!(Ray3::new(Vector3::new(1.0, 2.0, 0.0), Vector3::up()), ray);
+	/// let ray: Ray2 = ray.into();
+	/// assert_eq!(Ray2::new(Vector2::new(1.0, 2.0), Vector2::up()), ray);
+	/// ```
 	fn from(value: Ray2) -> Self {
 		Ray3::new(value.origin().to_vector3(), value.direction().to_vector3())
 	}
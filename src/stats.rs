@@ -0,0 +1,119 @@
+
+use crate::Math;
+
+/// Keeps track of the mean and variance of a stream of values without storing them,
+/// using Welford's algorithm to stay numerically stable
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunningAverage {
+	/// The number of values that have been pushed
+	count: u32,
+	/// The mean of all the values that have been pushed
+	mean: f32,
+	/// The sum of the squared differences from the mean
+	squared_differences: f32,
+}
+
+/// Constructors
+impl RunningAverage {
+	/// Creates a new running average with no values
+	/// 
+	/// **Returns**: Returns a new running average
+	/// #### Examples
+	/// ```
+	/// # use mathx::RunningAverage;
+	/// let average = RunningAverage::new();
+	/// assert_eq!(0, average.count());
+	/// assert_eq!(0.0, average.mean());
+	/// ```
+	pub fn new() -> Self { RunningAverage { count: 0, mean: 0.0, squared_differences: 0.0 } }
+}
+
+/// Properties
+impl RunningAverage {
+	/// Gets the number of values that have been pushed
+	/// 
+	/// **Returns**: Returns the number of values that have been pushed
+	/// #### Examples
+	/// ```
+	/// # use mathx::RunningAverage;
+	/// let mut average = RunningAverage::new();
+	/// average.push(1.0);
+	/// average.push(2.0);
+	/// assert_eq!(2, average.count());
+	/// ```
+	pub fn count(&self) -> u32 { self.count }
+	
+	/// Gets the mean of all the values that have been pushed
+	/// 
+	/// **Returns**: Returns the mean of the values, returns 0.0 if no values have been pushed
+	/// #### Examples
+	/// ```
+	/// # use mathx::RunningAverage;
+	/// let mut average = RunningAverage::new();
+	/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+	///     average.push(value);
+	/// }
+	/// assert_eq!(5.0, average.mean());
+	/// ```
+	pub fn mean(&self) -> f32 { self.mean }
+}
+
+/// Public Methods
+impl RunningAverage {
+	/// Adds the value to the running average
+	/// - **value**: The value to add
+	/// #### Examples
+	/// ```
+	/// # use mathx::RunningAverage;
+	/// let mut average = RunningAverage::new();
+	/// average.push(10.0);
+	/// assert_eq!(10.0, average.mean());
+	/// average.push(20.0);
+	/// assert_eq!(15.0, average.mean());
+	/// ```
+	pub fn push(&mut self, value: f32) {
+		self.count += 1;
+		
+		let delta = value - self.mean;
+		
+		self.mean += delta / self.count as f32;
+		self.squared_differences += delta * (value - self.mean);
+	}
+	
+	/// Gets the sample standard deviation of all the values that have been pushed
+	/// 
+	/// **Returns**: Returns the square root of the sample variance, returns 0.0 if less than two values have been pushed
+	/// #### Examples
+	/// ```
+	/// # use mathx::{RunningAverage,Math,assert_range};
+	/// let mut average = RunningAverage::new();
+	/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+	///     average.push(value);
+	/// }
+	/// assert_range!(2.13808993, average.std_dev());
+	/// ```
+	pub fn std_dev(&self) -> f32 { Math::sqrt(self.variance()) }
+	
+	/// Gets the sample variance of all the values that have been pushed, dividing by one less than the count
+	/// 
+	/// **Returns**: Returns the sample variance of the values, returns 0.0 if less than two values have been pushed
+	/// #### Examples
+	/// ```
+	/// # use mathx::{RunningAverage,Math,assert_range};
+	/// let mut average = RunningAverage::new();
+	/// for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+	///     average.push(value);
+	/// }
+	/// assert_range!(4.5714286, average.variance());
+	/// let mut average = RunningAverage::new();
+	/// average.push(3.0);
+	/// assert_eq!(0.0, average.variance());
+	/// ```
+	pub fn variance(&self) -> f32 {
+		if self.count < 2 { 0.0 }
+		else { self.squared_differences / (self.count - 1) as f32 }
+	}
+}
+
+unsafe impl Send for RunningAverage {}
+unsafe impl Sync for RunningAverage {}
@@ -4,3 +4,6 @@ pub use vector2::Vector2;
 
 mod vector3;
 pub use vector3::Vector3;
+
+mod vector4;
+pub use vector4::Vector4;
@@ -1,7 +1,10 @@
 
-use core::ops::Neg;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut, Neg};
 
-use crate::Math;
+use crate::{Exact, Math, ParseError, interfaces::ApproxEq, parse::parse_floats};
+#[cfg(feature = "random")]
+use crate::Random;
 use crate::Vector3;
 use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
 
@@ -45,6 +48,19 @@ impl Vector2 {
 	/// ```
 	pub fn from_vector3(vector: Vector3) -> Self { Vector2::new(vector.x(), vector.y()) }
 	
+	/// Creates a new 2D vector from an array of its components
+	/// - **array**: The array holding the components in x, y order
+	/// 
+	/// **Returns**: Returns a new 2D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::from_array([1.0, 2.0]);
+	/// assert_eq!(Vector2::new(1.0, 2.0), vector);
+	/// assert_eq!(vector, Vector2::from([1.0, 2.0]));
+	/// ```
+	pub fn from_array(array: [f32; 2]) -> Self { Vector2::new(array[0], array[1]) }
+	
 	/// Creates an empty 2D vector: (0, 0)
 	/// 
 	/// **Returns**: Returns an empty 2D vector
@@ -156,6 +172,26 @@ impl Vector2 {
 		
 		Vector2::new(cos, sin)
 	}
+	
+	/// Creates a random unit vector, uniformly distributed around a circle
+	/// - **random**: The random number generator to use
+	/// 
+	/// **Returns**: Returns a random 2D vector with a magnitude of 1
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Random,Math,assert_range};
+	/// let mut random = Random::new(42);
+	/// for _ in 0..10 {
+	///     assert_range!(1.0, Vector2::random_unit(&mut random).magnitude());
+	/// }
+	/// assert_eq!(Vector2::random_unit(&mut Random::new(7)), Vector2::random_unit(&mut Random::new(7)));
+	/// ```
+	#[cfg(feature = "random")]
+	pub fn random_unit(random: &mut Random) -> Self {
+		let (sin, cos) = Math::sin_cos(random.range(0.0, Math::TWO_PI));
+		
+		Vector2::new(cos, sin)
+	}
 }
 
 /// Properties
@@ -178,6 +214,19 @@ impl Vector2 {
 	/// - **value**: The value to set the y coordinate of the vector
 	pub fn set_y(&mut self, value: f32) { self.y = value; }
 	
+	/// Gets the angle of the vector from the positive x-axis in radians, the same as `heading`
+	/// 
+	/// **Returns**: Returns the angle of the vector in radians
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Math,Vector2,assert_range};
+	/// assert_range!(Math::PI_OVER_2, Vector2::up().angle());
+	/// assert_range!(0.0, Vector2::right().angle());
+	/// assert_range!(Math::PI, Vector2::left().angle());
+	/// assert_range!(-Math::PI_OVER_4, Vector2::new(1.0, -1.0).angle());
+	/// ```
+	pub fn angle(&self) -> f32 { self.heading() }
+	
 	/// Get the heading from the vector in radians
 	/// 
 	/// **Returns**: Returns the heading from the vector in radians
@@ -263,6 +312,18 @@ impl Vector2 {
 
 /// Public Methods
 impl Vector2 {
+	/// Gets the absolute value of each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with only positive components
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(-1.0, -0.0).abs();
+	/// assert_eq!(Vector2::new(1.0, 0.0), vector);
+	/// assert!(vector.y().is_sign_positive());
+	/// ```
+	pub fn abs(self) -> Self { Vector2::new(Math::abs(self.x), Math::abs(self.y)) }
+	
 	/// Gets the angle between the two vectors in radians
 	/// - **rhs**: The other vector to get the angle from
 	/// 
@@ -294,6 +355,218 @@ impl Vector2 {
 	/// ```
 	pub fn angle_between_deg(self, rhs: Vector2) -> f32 { return Math::rad2deg(self.angle_between(rhs)); }
 	
+	/// Approximately compares this vector with the expected vector, describing the difference when they diverge
+	/// - **expected**: The vector that this vector is expected to be
+	/// - **epsilon**: The epsilon (smallest possible difference between numbers) to check with
+	/// 
+	/// **Returns**: Returns `Ok` if every component is approximately equal, otherwise returns an `Err` naming
+	/// the first component that diverges and by how much
+	/// #### Remarks
+	/// This allocates the error message and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let a = Vector2::new(1.0, 2.0);
+	/// assert_eq!(Ok(()), a.assert_approx(&Vector2::new(1.0001, 2.0), 0.001));
+	/// let error = a.assert_approx(&Vector2::new(-1.0, 2.0), 0.001).unwrap_err();
+	/// assert_eq!("x differs by 2 (expected -1, actual 1)", error);
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn assert_approx(&self, expected: &Vector2, epsilon: f32) -> Result<(), String> {
+		Math::approx_components(&[
+			("x", expected.x, self.x),
+			("y", expected.y, self.y),
+		], epsilon)
+	}
+	
+	/// Gets the point on a cubic Bezier curve
+	/// - **p0**: The point where the curve starts
+	/// - **p1**: The first control point, pulling the start of the curve towards it
+	/// - **p2**: The second control point, pulling the end of the curve towards it
+	/// - **p3**: The point where the curve ends
+	/// - **t**: The ratio along the curve, where 0.0 is `p0` and 1.0 is `p3`
+	/// 
+	/// **Returns**: Returns the point on the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let p0 = Vector2::new(0.0, 0.0);
+	/// let p1 = Vector2::new(0.0, 1.0);
+	/// let p2 = Vector2::new(1.0, 1.0);
+	/// let p3 = Vector2::new(1.0, 0.0);
+	/// assert_eq!(p0, Vector2::bezier(p0, p1, p2, p3, 0.0));
+	/// assert_eq!(p3, Vector2::bezier(p0, p1, p2, p3, 1.0));
+	/// assert_eq!(Vector2::new(0.5, 0.75), Vector2::bezier(p0, p1, p2, p3, 0.5));
+	/// ```
+	pub fn bezier(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+		let u = 1.0 - t;
+		
+		(u * u * u) * p0 + (3.0 * u * u * t) * p1 + (3.0 * u * t * t) * p2 + (t * t * t) * p3
+	}
+	
+	/// Gets the tangent of a cubic Bezier curve, which is the derivative of `bezier`
+	/// - **p0**: The point where the curve starts
+	/// - **p1**: The first control point, pulling the start of the curve towards it
+	/// - **p2**: The second control point, pulling the end of the curve towards it
+	/// - **p3**: The point where the curve ends
+	/// - **t**: The ratio along the curve, where 0.0 is `p0` and 1.0 is `p3`
+	/// 
+	/// **Returns**: Returns the tangent of the curve, pointing in the direction the curve is moving
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let p0 = Vector2::new(0.0, 0.0);
+	/// let p1 = Vector2::new(0.0, 1.0);
+	/// let p2 = Vector2::new(1.0, 1.0);
+	/// let p3 = Vector2::new(1.0, 0.0);
+	/// let tangent = Vector2::bezier_derivative(p0, p1, p2, p3, 0.0);
+	/// assert_eq!((p1 - p0).normalize(), tangent.normalize());
+	/// let tangent = Vector2::bezier_derivative(p0, p1, p2, p3, 1.0);
+	/// assert_eq!((p3 - p2).normalize(), tangent.normalize());
+	/// assert_eq!(Vector2::new(1.5, 0.0), Vector2::bezier_derivative(p0, p1, p2, p3, 0.5));
+	/// ```
+	pub fn bezier_derivative(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+		let u = 1.0 - t;
+		
+		(3.0 * u * u) * (p1 - p0) + (6.0 * u * t) * (p2 - p1) + (3.0 * t * t) * (p3 - p2)
+	}
+	
+	/// Bounces this vector (as an incoming velocity) off of a surface, losing energy along the normal
+	/// - **normal**: The normal vector of the surface to bounce off of
+	/// - **restitution**: How much of the speed along the normal is kept, where 0.0 slides along the surface and 1.0 is a perfect bounce
+	/// 
+	/// **Returns**: Returns the bounced vector
+	/// #### Remarks
+	/// The vector is split into the part that is along the normal and the part tangential to the surface,
+	/// the tangential part is kept as is while the normal part is flipped and scaled by the restitution.
+	/// With a restitution of 1.0 this is the same as `reflect`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let velocity = Vector2::new(3.0, -4.0);
+	/// let expected = Vector2::new(3.0, 2.0);
+	/// assert_eq!(expected, velocity.bounce(Vector2::up(), 0.5));
+	/// let expected = Vector2::new(3.0, 0.0);
+	/// assert_eq!(expected, velocity.bounce(Vector2::up(), 0.0));
+	/// assert_eq!(velocity.reflect(Vector2::up()), velocity.bounce(Vector2::up(), 1.0));
+	/// ```
+	pub fn bounce(self, normal: Vector2, restitution: f32) -> Self {
+		let perpendicular = self.project(normal);
+		let tangent = self - perpendicular;
+		
+		tangent - restitution * perpendicular
+	}
+	
+	/// Interpolates along a Catmull-Rom spline segment going from `p1` to `p2`
+	/// - **p0**: The point before the segment, used for the starting tangent
+	/// - **p1**: The point where the segment starts
+	/// - **p2**: The point where the segment ends
+	/// - **p3**: The point after the segment, used for the ending tangent
+	/// - **t**: The ratio along the segment, where 0.0 is `p1` and 1.0 is `p2`
+	/// 
+	/// **Returns**: Returns the point on the spline segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let p0 = Vector2::new(0.0, 0.0);
+	/// let p1 = Vector2::new(1.0, 0.0);
+	/// let p2 = Vector2::new(2.0, 1.0);
+	/// let p3 = Vector2::new(3.0, 1.0);
+	/// assert_eq!(p1, Vector2::catmull_rom(p0, p1, p2, p3, 0.0));
+	/// assert_eq!(p2, Vector2::catmull_rom(p0, p1, p2, p3, 1.0));
+	/// assert_eq!(Vector2::new(1.5, 0.5), Vector2::catmull_rom(p0, p1, p2, p3, 0.5));
+	/// ```
+	pub fn catmull_rom(p0: Vector2, p1: Vector2, p2: Vector2, p3: Vector2, t: f32) -> Vector2 {
+		let t2 = t * t;
+		let t3 = t2 * t;
+		
+		0.5 * (
+			2.0 * p1
+			+ t * (p2 - p0)
+			+ t2 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3)
+			+ t3 * (3.0 * p1 - p0 - 3.0 * p2 + p3)
+		)
+	}
+	
+	/// Clamps each component of this vector between the components of the min and max vectors
+	/// - **min**: The vector holding the lowest value of each component
+	/// - **max**: The vector holding the highest value of each component
+	/// 
+	/// **Returns**: Returns the clamped vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(-2.0, 0.5);
+	/// let expected = Vector2::new(-1.0, 0.5);
+	/// assert_eq!(expected, vector.clamp(-Vector2::one(), Vector2::one()));
+	/// let vector = Vector2::new(0.25, -0.5);
+	/// assert_eq!(vector, vector.clamp(-Vector2::one(), 2.0 * Vector2::one()));
+	/// ```
+	pub fn clamp(self, min: Vector2, max: Vector2) -> Self {
+		Vector2::new(
+			Math::clamp(self.x, min.x, max.x),
+			Math::clamp(self.y, min.y, max.y)
+		)
+	}
+	
+	/// Clamps the magnitude of this vector so that it's never longer than the max length
+	/// - **max**: The maximum length the vector can have
+	/// 
+	/// **Returns**: Returns the same vector if it's not longer than the max length, otherwise returns the vector scaled down to the max length
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::new(3.0, 4.0).clamp_magnitude(2.5);
+	/// assert_eq!(Vector2::new(1.5, 2.0), vector);
+	/// assert_range!(2.5, vector.magnitude());
+	/// let vector = Vector2::new(1.0, 2.0).clamp_magnitude(5.0);
+	/// assert_eq!(Vector2::new(1.0, 2.0), vector);
+	/// assert_eq!(Vector2::zero(), Vector2::zero().clamp_magnitude(1.0));
+	/// ```
+	pub fn clamp_magnitude(self, max: f32) -> Self {
+		let magnitude = self.magnitude();
+		
+		if magnitude <= max || magnitude == 0.0 { self }
+		else { (max / magnitude) * self }
+	}
+	
+	/// Divides each component of the vector by the matching component of the other vector
+	/// - **rhs**: The other vector to divide with
+	/// 
+	/// **Returns**: Returns the component-wise divided vector
+	/// #### Remarks
+	/// Any component divided by zero becomes 0, the same as dividing the whole vector by a scalar of zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let a = Vector2::new(2.0, -4.0);
+	/// let b = Vector2::new(4.0, 0.0);
+	/// assert_eq!(Vector2::new(0.5, 0.0), a.component_div(b));
+	/// let c = Vector2::new(4.0, 2.0);
+	/// assert_eq!(a, a.component_mul(c).component_div(c));
+	/// ```
+	pub fn component_div(self, rhs: Vector2) -> Self {
+		Vector2::new(
+			if rhs.x == 0.0 { 0.0 } else { self.x / rhs.x },
+			if rhs.y == 0.0 { 0.0 } else { self.y / rhs.y }
+		)
+	}
+	
+	/// Multiplies each component of the vector with the matching component of the other vector,
+	/// also known as the Hadamard product. This is the same as `scale`
+	/// - **rhs**: The other vector to multiply with
+	/// 
+	/// **Returns**: Returns the component-wise multiplied vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let a = Vector2::new(2.0, -4.0);
+	/// let b = Vector2::new(4.0, 0.5);
+	/// assert_eq!(Vector2::new(8.0, -2.0), a.component_mul(b));
+	/// assert_eq!(a.scale(b), a.component_mul(b));
+	/// ```
+	pub fn component_mul(self, rhs: Vector2) -> Self { self.scale(rhs) }
+	
 	/// Gets the distance between the two vectors
 	/// - **rhs**: The other vector to get the distance between
 	/// 
@@ -346,6 +619,36 @@ impl Vector2 {
 		self.x * rhs.x + self.y * rhs.y
 	}
 	
+	/// Finds if all the components of the vector are finite, being neither NaN nor infinite
+	/// 
+	/// **Returns**: Returns true if all the components are finite
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(1.0, -2.0);
+	/// assert!(vector.is_finite());
+	/// let vector = Vector2::new(f32::NAN, 3.0);
+	/// assert!(!vector.is_finite());
+	/// let vector = Vector2::new(0.0, f32::INFINITY);
+	/// assert!(!vector.is_finite());
+	/// ```
+	pub fn is_finite(&self) -> bool { self.to_array().iter().all(|value| value.is_finite()) }
+	
+	/// Finds if the vector is a unit vector, having a magnitude of approximately 1
+	/// 
+	/// **Returns**: Returns true if the vector is normalized
+	/// #### Remarks
+	/// This checks the square magnitude, so it avoids computing a square root
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(1.0, 2.0);
+	/// assert!(vector.normalize().is_normalized());
+	/// assert!(!(2.0 * vector.normalize()).is_normalized());
+	/// assert!(!Vector2::zero().is_normalized());
+	/// ```
+	pub fn is_normalized(&self) -> bool { Math::approx(self.square_magnitude(), 1.0) }
+	
 	/// Linearly interpolates between the this and the other vector
 	/// - **rhs**: The other vector to end from
 	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
@@ -381,6 +684,32 @@ impl Vector2 {
 		)
 	}
 	
+	/// Gets the largest value of each component between the two vectors
+	/// - **rhs**: The other vector to compare with
+	/// 
+	/// **Returns**: Returns the vector holding the largest value of each component
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let a = Vector2::new(1.0, -2.0);
+	/// let b = Vector2::new(-1.0, 2.0);
+	/// assert_eq!(Vector2::new(1.0, 2.0), a.max(b));
+	/// ```
+	pub fn max(self, rhs: Vector2) -> Self { Vector2::new(Math::max(self.x, rhs.x), Math::max(self.y, rhs.y)) }
+	
+	/// Gets the smallest value of each component between the two vectors
+	/// - **rhs**: The other vector to compare with
+	/// 
+	/// **Returns**: Returns the vector holding the smallest value of each component
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let a = Vector2::new(1.0, -2.0);
+	/// let b = Vector2::new(-1.0, 2.0);
+	/// assert_eq!(Vector2::new(-1.0, -2.0), a.min(b));
+	/// ```
+	pub fn min(self, rhs: Vector2) -> Self { Vector2::new(Math::min(self.x, rhs.x), Math::min(self.y, rhs.y)) }
+	
 	/// Moves this vector towards the target vector, it will never move past the target
 	/// - **target**: The target vector to move towards
 	/// - **delta**: The delta distance to try and move with, defines the maximum distance moved
@@ -422,6 +751,29 @@ impl Vector2 {
 	/// ```
 	pub fn normalize(self) -> Self { self / self.magnitude() }
 	
+	/// Normalizes the vector while also returning the original magnitude, using only a single square root
+	/// 
+	/// **Returns**: Returns a tuple of the unit vector version of this vector and the original magnitude,
+	/// returns a zero vector with a zero magnitude if this vector is a zero vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::new(3.0, 4.0);
+	/// let (unit, magnitude) = vector.normalize_with_magnitude();
+	/// assert_eq!(vector.normalize(), unit);
+	/// assert_range!(vector.magnitude(), magnitude);
+	/// assert_range!(5.0, magnitude);
+	/// let (unit, magnitude) = Vector2::zero().normalize_with_magnitude();
+	/// assert_eq!(Vector2::zero(), unit);
+	/// assert_eq!(0.0, magnitude);
+	/// ```
+	pub fn normalize_with_magnitude(self) -> (Self, f32) {
+		let magnitude = self.magnitude();
+		
+		if magnitude == 0.0 { (Vector2::zero(), 0.0) }
+		else { (self / magnitude, magnitude) }
+	}
+	
 	/// Creates a perpendicular 2D vector
 	/// 
 	/// **Returns**: Returns a perpendicular 2D vector
@@ -493,6 +845,48 @@ impl Vector2 {
 		return dot * normal + self;
 	}
 	
+	/// Keeps a moving point inside of a rectangle, clamping the position back into the rectangle and
+	/// reflecting the velocity on each axis that the point left the rectangle through
+	/// - **min**: The minimum corner of the rectangle
+	/// - **max**: The maximum corner of the rectangle
+	/// - **position**: The position of the point
+	/// - **velocity**: The velocity of the point
+	/// 
+	/// **Returns**: Returns the corrected position and velocity, which are unchanged if the position is inside the rectangle
+	/// #### Remarks
+	/// This is the 2D version of `Bounds3::reflect_inside`.
+	/// Each reflected component is flipped to point back into the rectangle, so a point that's outside while
+	/// already moving back towards the rectangle keeps it's velocity on that axis
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let min = Vector2::new(-1.0, -1.0);
+	/// let max = Vector2::one();
+	/// let (position, velocity) = Vector2::reflect_inside_rect(min, max, Vector2::new(1.25, 0.5), Vector2::new(2.0, 1.0));
+	/// assert_eq!(Vector2::new(1.0, 0.5), position);
+	/// assert_eq!(Vector2::new(-2.0, 1.0), velocity);
+	/// let (position, velocity) = Vector2::reflect_inside_rect(min, max, Vector2::new(0.5, -0.5), Vector2::new(2.0, 1.0));
+	/// assert_eq!(Vector2::new(0.5, -0.5), position);
+	/// assert_eq!(Vector2::new(2.0, 1.0), velocity);
+	/// ```
+	pub fn reflect_inside_rect(min: Vector2, max: Vector2, position: Vector2, velocity: Vector2) -> (Vector2, Vector2) {
+		let mut position = position;
+		let mut velocity = velocity;
+		
+		for axis in 0..2 {
+			if position[axis] < min[axis] {
+				position[axis] = min[axis];
+				velocity[axis] = Math::abs(velocity[axis]);
+			}
+			else if position[axis] > max[axis] {
+				position[axis] = max[axis];
+				velocity[axis] = -Math::abs(velocity[axis]);
+			}
+		}
+		
+		(position, velocity)
+	}
+	
 	/// Scales the vector using another vector, multiplying everything component-wise
 	/// - **rhs**: The other vector to scale with
 	/// 
@@ -543,13 +937,67 @@ impl Vector2 {
 	/// ```
 	pub fn signed_angle_between_deg(self, rhs: Vector2) -> f32 { Math::rad2deg(self.signed_angle_between(rhs)) }
 	
+	/// Normalizes the vector, failing if the vector is too close to zero to have a direction
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector, or `None` if the vector is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::new(3.0, 4.0).try_normalize();
+	/// assert_eq!(Some(Vector2::new(0.6, 0.8)), vector);
+	/// assert_range!(1.0, vector.unwrap().magnitude());
+	/// assert_eq!(None, Vector2::zero().try_normalize());
+	/// ```
+	pub fn try_normalize(self) -> Option<Self> {
+		let magnitude = self.magnitude();
+		
+		if Math::approx_zero(magnitude) { None }
+		else { Some(self / magnitude) }
+	}
+	
+	/// Rescales this vector so that it's exactly the given length, keeping it's direction
+	/// - **length**: The length the vector should have
+	/// 
+	/// **Returns**: Returns the vector scaled to the given length, returns a zero vector if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector2,Math,assert_range};
+	/// let vector = Vector2::new(3.0, 4.0);
+	/// let scaled = vector.with_magnitude(10.0);
+	/// assert_eq!(Vector2::new(6.0, 8.0), scaled);
+	/// assert_range!(10.0, scaled.magnitude());
+	/// assert_eq!(vector.normalize(), scaled.normalize());
+	/// assert_eq!(Vector2::zero(), Vector2::zero().with_magnitude(10.0));
+	/// ```
+	pub fn with_magnitude(self, length: f32) -> Self { length * self.normalize() }
 }
 
 /// Conversions
 impl Vector2 {
+	/// Converts the vector into an array of its components
+	/// 
+	/// **Returns**: Returns the array holding the components in x, y order
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector2;
+	/// let vector = Vector2::new(1.0, 2.0);
+	/// assert_eq!([1.0, 2.0], vector.to_array());
+	/// let array: [f32; 2] = vector.into();
+	/// assert_eq!(vector, Vector2::from_array(array));
+	/// ```
+	pub fn to_array(self) -> [f32; 2] { [self.x, self.y] }
+	
 	pub fn to_vector3(self) -> Vector3 { Vector3::new(self.x, self.y, 0.0) }
 }
 
+impl From<[f32; 2]> for Vector2 {
+	fn from(value: [f32; 2]) -> Self { Vector2::from_array(value) }
+}
+
+impl From<Vector2> for [f32; 2] {
+	fn from(value: Vector2) -> Self { value.to_array() }
+}
+
 impl From<Vector3> for Vector2 {
 	fn from(value: Vector3) -> Self { Vector2::from_vector3(value) }
 }
@@ -557,15 +1005,61 @@ impl From<Vector3> for Vector2 {
 unsafe impl Send for Vector2 {}
 unsafe impl Sync for Vector2 {}
 
+// Default
+/// Gets the zero vector, the same as `Vector2::zero()`
+/// #### Examples
+/// ```
+/// # use mathx::Vector2;
+/// assert_eq!(Vector2::zero(), Vector2::default());
+/// ```
+impl Default for Vector2 {
+	fn default() -> Self { Vector2::zero() }
+}
+
 // Equates
 impl Eq for Vector2 {}
 impl PartialEq for Vector2 {
-	fn eq(&self, other: &Self) -> bool {
-		Math::approx(self.x, other.x)
-		&& Math::approx(self.y, other.y)
+	fn eq(&self, other: &Self) -> bool { self.approx_eq(other) }
+}
+
+/// Approximately compares each component of the vector
+/// #### Examples
+/// ```
+/// # use mathx::Vector2;
+/// # use mathx::interfaces::ApproxEq;
+/// let a = Vector2::new(1.0, 2.0);
+/// let b = Vector2::new(1.0001, 2.0);
+/// assert!(a.approx_eq_eps(&b, 0.001));
+/// assert!(!a.approx_eq(&b));
+/// ```
+impl ApproxEq for Vector2 {
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool {
+		Math::approx_epsilon(self.x, other.x, epsilon)
+		&& Math::approx_epsilon(self.y, other.y, epsilon)
 	}
 }
 
+// Hashing
+/// Hashes the exact bits of each component, allowing the vector to be used as a key
+/// #### Examples
+/// ```
+/// # use std::collections::HashMap;
+/// # use mathx::{Exact,Vector2};
+/// let mut map = HashMap::new();
+/// map.insert(Exact(Vector2::new(1.0, 2.0)), 10);
+/// assert_eq!(Some(&10), map.get(&Exact(Vector2::new(1.0, 2.0))));
+/// assert_eq!(Vector2::new(-0.0, 2.0), Vector2::new(0.0, 2.0));
+/// assert_ne!(Exact(Vector2::new(-0.0, 2.0)), Exact(Vector2::new(0.0, 2.0)));
+/// ```
+impl Hash for Exact<Vector2> {
+	fn hash<H: Hasher>(&self, state: &mut H) { self.0.to_array().map(f32::to_bits).hash(state); }
+}
+
+impl Eq for Exact<Vector2> {}
+impl PartialEq for Exact<Vector2> {
+	fn eq(&self, other: &Self) -> bool { self.0.to_array().map(f32::to_bits) == other.0.to_array().map(f32::to_bits) }
+}
+
 // Display
 #[cfg(not(feature = "no_std"))]
 impl std::fmt::Display for Vector2 {
@@ -574,6 +1068,82 @@ impl std::fmt::Display for Vector2 {
 	}
 }
 
+// Parsing
+/// Parses the vector from comma-separated numbers that can be surrounded by parentheses, such as `(1, 2)`
+/// #### Remarks
+/// This accepts the same format as the `Display` output, so `vector.to_string().parse()` gives back the same vector
+/// #### Examples
+/// ```
+/// # use mathx::{Vector2,ParseError};
+/// let vector: Vector2 = "1.5, -2".parse().unwrap();
+/// assert_eq!(Vector2::new(1.5, -2.0), vector);
+/// let vector: Vector2 = "(1.5, -2.0)".parse().unwrap();
+/// assert_eq!(Vector2::new(1.5, -2.0), vector);
+/// # #[cfg(not(feature = "no_std"))] {
+/// let vector = Vector2::new(0.1, 2.5);
+/// assert_eq!(Ok(vector), vector.to_string().parse());
+/// # }
+/// assert_eq!(Err(ParseError::WrongCount { expected: 2, found: 3 }), "1, 2, 3".parse::<Vector2>());
+/// assert_eq!(Err(ParseError::InvalidNumber { index: 1 }), "(1, y)".parse::<Vector2>());
+/// assert_eq!(Err(ParseError::UnbalancedParentheses), "(1, 2".parse::<Vector2>());
+/// ```
+impl core::str::FromStr for Vector2 {
+	type Err = ParseError;
+	
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let values = parse_floats(text, ["", ""])?;
+		
+		Ok(Vector2::new(values[0], values[1]))
+	}
+}
+
+// Indexing
+/// Gets the component of the vector by index, where 0 is x and 1 is y
+/// #### Remarks
+/// This panics if the index is out of range
+/// #### Examples
+/// ```
+/// # use mathx::Vector2;
+/// let vector = Vector2::new(1.0, 2.0);
+/// assert_eq!(1.0, vector[0]);
+/// assert_eq!(3.0, (0..2).map(|i| vector[i]).sum::<f32>());
+/// ```
+/// ```should_panic
+/// # use mathx::Vector2;
+/// let vector = Vector2::new(1.0, 2.0);
+/// let _ = vector[2];
+/// ```
+impl Index<usize> for Vector2 {
+	type Output = f32;
+	fn index(&self, index: usize) -> &Self::Output {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			_ => panic!("Vector2 index out of range: {} (expected 0 to 1)", index),
+		}
+	}
+}
+
+/// Gets the mutable component of the vector by index, where 0 is x and 1 is y
+/// #### Remarks
+/// This panics if the index is out of range
+/// #### Examples
+/// ```
+/// # use mathx::Vector2;
+/// let mut vector = Vector2::new(1.0, 2.0);
+/// vector[1] = 5.0;
+/// assert_eq!(Vector2::new(1.0, 5.0), vector);
+/// ```
+impl IndexMut<usize> for Vector2 {
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		match index {
+			0 => &mut self.x,
+			1 => &mut self.y,
+			_ => panic!("Vector2 index out of range: {} (expected 0 to 1)", index),
+		}
+	}
+}
+
 // Arithmetic
 impl AddSubArithmetic<Vector2> for Vector2 {
 	type Output = Vector2;
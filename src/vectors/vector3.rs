@@ -1,8 +1,11 @@
 
-use core::ops::Neg;
+use core::hash::{Hash, Hasher};
+use core::ops::{Index, IndexMut, Neg};
 
-use crate::Math;
-use crate::Vector2;
+use crate::{Exact, Math, ParseError, interfaces::ApproxEq, parse::parse_floats};
+#[cfg(feature = "random")]
+use crate::Random;
+use crate::{Vector2, Vector4};
 use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
 
 /// A 3D vector that holds an x-coordinate, y-coordinate, and z-coordinate
@@ -50,6 +53,19 @@ impl Vector3 {
 	/// ```
 	pub fn from_vector2(vector: Vector2) -> Self { Vector3::new(vector.x(), vector.y(), 0.0) }
 	
+	/// Creates a new 3D vector from an array of its components
+	/// - **array**: The array holding the components in x, y, z order
+	/// 
+	/// **Returns**: Returns a new 3D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::from_array([1.0, 2.0, 3.0]);
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), vector);
+	/// assert_eq!(vector, Vector3::from([1.0, 2.0, 3.0]));
+	/// ```
+	pub fn from_array(array: [f32; 3]) -> Self { Vector3::new(array[0], array[1], array[2]) }
+	
 	/// Creates an empty 3D vector
 	/// 
 	/// **Returns**: Returns an empty 3D vector
@@ -206,6 +222,28 @@ impl Vector3 {
 	pub fn from_angles_deg(theta: f32, phi: f32) -> Self {
 		Vector3::from_angles(Math::deg2rad(theta), Math::deg2rad(phi))
 	}
+	
+	/// Creates a random unit vector, uniformly distributed over the surface of a sphere
+	/// - **random**: The random number generator to use
+	/// 
+	/// **Returns**: Returns a random 3D vector with a magnitude of 1
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Random,Math,assert_range};
+	/// let mut random = Random::new(42);
+	/// for _ in 0..10 {
+	///     assert_range!(1.0, Vector3::random_unit(&mut random).magnitude());
+	/// }
+	/// assert_eq!(Vector3::random_unit(&mut Random::new(7)), Vector3::random_unit(&mut Random::new(7)));
+	/// ```
+	#[cfg(feature = "random")]
+	pub fn random_unit(random: &mut Random) -> Self {
+		let z = random.range(-1.0, 1.0);
+		let (sin, cos) = Math::sin_cos(random.range(0.0, Math::TWO_PI));
+		let radius = Math::sqrt(1.0 - z * z);
+		
+		Vector3::new(radius * cos, radius * sin, z)
+	}
 }
 
 /// Properties
@@ -277,6 +315,18 @@ impl Vector3 {
 
 /// Public Methods
 impl Vector3 {
+	/// Gets the absolute value of each component of the vector
+	/// 
+	/// **Returns**: Returns the vector with only positive components
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(-1.0, 2.0, -0.0).abs();
+	/// assert_eq!(Vector3::new(1.0, 2.0, 0.0), vector);
+	/// assert!(vector.z().is_sign_positive());
+	/// ```
+	pub fn abs(self) -> Self { Vector3::new(Math::abs(self.x), Math::abs(self.y), Math::abs(self.z)) }
+	
 	/// Gets the angle between the two vectors in radians
 	/// - **rhs**: The other vector to get the angle from
 	/// 
@@ -308,6 +358,260 @@ impl Vector3 {
 	/// ```
 	pub fn angle_between_deg(self, rhs: Vector3) -> f32 { return Math::rad2deg(self.angle_between(rhs)); }
 	
+	/// Gets the unsigned angle between the vector and the given axis in radians
+	/// - **axis**: The axis to get the angle from
+	/// 
+	/// **Returns**: Returns the angle between the vector and the axis in radians, between 0 and pi
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let vector = Vector3::new(1.0, 1.0, 0.0);
+	/// assert_range!(Math::PI_OVER_4, vector.angle_to_axis(Vector3::right()));
+	/// assert_range!(Math::PI_OVER_2, vector.angle_to_axis(Vector3::forward()));
+	/// assert_range!(Math::PI, Vector3::down().angle_to_axis(Vector3::up()));
+	/// ```
+	pub fn angle_to_axis(self, axis: Vector3) -> f32 { self.angle_between(axis) }
+	
+	/// Approximately compares this vector with the expected vector, describing the difference when they diverge
+	/// - **expected**: The vector that this vector is expected to be
+	/// - **epsilon**: The epsilon (smallest possible difference between numbers) to check with
+	/// 
+	/// **Returns**: Returns `Ok` if every component is approximately equal, otherwise returns an `Err` naming
+	/// the first component that diverges and by how much
+	/// #### Remarks
+	/// This allocates the error message and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(1.0, 2.0, 3.0);
+	/// assert_eq!(Ok(()), a.assert_approx(&Vector3::new(1.0, 2.0, 3.0001), 0.001));
+	/// let error = a.assert_approx(&Vector3::new(1.0, 2.5, 3.0), 0.001).unwrap_err();
+	/// assert_eq!("y differs by 0.5 (expected 2.5, actual 2)", error);
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn assert_approx(&self, expected: &Vector3, epsilon: f32) -> Result<(), String> {
+		Math::approx_components(&[
+			("x", expected.x, self.x),
+			("y", expected.y, self.y),
+			("z", expected.z, self.z),
+		], epsilon)
+	}
+	
+	/// Gets the point within the triangle from it's barycentric coordinates
+	/// - **a**: The first corner of the triangle
+	/// - **b**: The second corner of the triangle
+	/// - **c**: The third corner of the triangle
+	/// - **u**: The weight of the second corner
+	/// - **v**: The weight of the third corner
+	/// 
+	/// **Returns**: Returns the point computed as `a + u * (b - a) + v * (c - a)`
+	/// #### Remarks
+	/// The weight of the first corner is implicitly `1 - u - v`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(0.0, 0.0, 0.0);
+	/// let b = Vector3::new(2.0, 0.0, 0.0);
+	/// let c = Vector3::new(0.0, 4.0, 1.0);
+	/// assert_eq!(a, Vector3::barycentric(a, b, c, 0.0, 0.0));
+	/// assert_eq!(b, Vector3::barycentric(a, b, c, 1.0, 0.0));
+	/// assert_eq!(c, Vector3::barycentric(a, b, c, 0.0, 1.0));
+	/// assert_eq!(Vector3::new(0.5, 2.0, 0.5), Vector3::barycentric(a, b, c, 0.25, 0.5));
+	/// ```
+	pub fn barycentric(a: Vector3, b: Vector3, c: Vector3, u: f32, v: f32) -> Vector3 {
+		a + u * (b - a) + v * (c - a)
+	}
+	
+	/// Gets the point on a cubic Bezier curve
+	/// - **p0**: The point where the curve starts
+	/// - **p1**: The first control point, pulling the start of the curve towards it
+	/// - **p2**: The second control point, pulling the end of the curve towards it
+	/// - **p3**: The point where the curve ends
+	/// - **t**: The ratio along the curve, where 0.0 is `p0` and 1.0 is `p3`
+	/// 
+	/// **Returns**: Returns the point on the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let p0 = Vector3::new(0.0, 0.0, 0.0);
+	/// let p1 = Vector3::new(0.0, 1.0, 0.0);
+	/// let p2 = Vector3::new(1.0, 1.0, 0.0);
+	/// let p3 = Vector3::new(1.0, 0.0, 1.0);
+	/// assert_eq!(p0, Vector3::bezier(p0, p1, p2, p3, 0.0));
+	/// assert_eq!(p3, Vector3::bezier(p0, p1, p2, p3, 1.0));
+	/// assert_eq!(Vector3::new(0.5, 0.75, 0.125), Vector3::bezier(p0, p1, p2, p3, 0.5));
+	/// ```
+	pub fn bezier(p0: Vector3, p1: Vector3, p2: Vector3, p3: Vector3, t: f32) -> Vector3 {
+		let u = 1.0 - t;
+		
+		(u * u * u) * p0 + (3.0 * u * u * t) * p1 + (3.0 * u * t * t) * p2 + (t * t * t) * p3
+	}
+	
+	/// Gets the tangent of a cubic Bezier curve, which is the derivative of `bezier`
+	/// - **p0**: The point where the curve starts
+	/// - **p1**: The first control point, pulling the start of the curve towards it
+	/// - **p2**: The second control point, pulling the end of the curve towards it
+	/// - **p3**: The point where the curve ends
+	/// - **t**: The ratio along the curve, where 0.0 is `p0` and 1.0 is `p3`
+	/// 
+	/// **Returns**: Returns the tangent of the curve, pointing in the direction the curve is moving
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let p0 = Vector3::new(0.0, 0.0, 0.0);
+	/// let p1 = Vector3::new(0.0, 1.0, 0.0);
+	/// let p2 = Vector3::new(1.0, 1.0, 0.0);
+	/// let p3 = Vector3::new(1.0, 0.0, 1.0);
+	/// let tangent = Vector3::bezier_derivative(p0, p1, p2, p3, 0.0);
+	/// assert_eq!((p1 - p0).normalize(), tangent.normalize());
+	/// let tangent = Vector3::bezier_derivative(p0, p1, p2, p3, 1.0);
+	/// assert_eq!((p3 - p2).normalize(), tangent.normalize());
+	/// assert_eq!(Vector3::new(1.5, 0.0, 0.75), Vector3::bezier_derivative(p0, p1, p2, p3, 0.5));
+	/// ```
+	pub fn bezier_derivative(p0: Vector3, p1: Vector3, p2: Vector3, p3: Vector3, t: f32) -> Vector3 {
+		let u = 1.0 - t;
+		
+		(3.0 * u * u) * (p1 - p0) + (6.0 * u * t) * (p2 - p1) + (3.0 * t * t) * (p3 - p2)
+	}
+	
+	/// Bounces this vector (as an incoming velocity) off of a surface, losing energy along the normal
+	/// - **normal**: The normal vector of the surface to bounce off of
+	/// - **restitution**: How much of the speed along the normal is kept, where 0.0 slides along the surface and 1.0 is a perfect bounce
+	/// 
+	/// **Returns**: Returns the bounced vector
+	/// #### Remarks
+	/// The vector is split into the part that is along the normal and the part tangential to the surface,
+	/// the tangential part is kept as is while the normal part is flipped and scaled by the restitution.
+	/// With a restitution of 1.0 this is the same as `reflect`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let velocity = Vector3::new(2.0, -4.0, 1.0);
+	/// let expected = Vector3::new(2.0, 2.0, 1.0);
+	/// assert_eq!(expected, velocity.bounce(Vector3::up(), 0.5));
+	/// let expected = Vector3::new(2.0, 0.0, 1.0);
+	/// assert_eq!(expected, velocity.bounce(Vector3::up(), 0.0));
+	/// assert_eq!(velocity.reflect(Vector3::up()), velocity.bounce(Vector3::up(), 1.0));
+	/// ```
+	pub fn bounce(self, normal: Vector3, restitution: f32) -> Self {
+		let perpendicular = self.project(normal);
+		let tangent = self - perpendicular;
+		
+		tangent - restitution * perpendicular
+	}
+	
+	/// Interpolates along a Catmull-Rom spline segment going from `p1` to `p2`
+	/// - **p0**: The point before the segment, used for the starting tangent
+	/// - **p1**: The point where the segment starts
+	/// - **p2**: The point where the segment ends
+	/// - **p3**: The point after the segment, used for the ending tangent
+	/// - **t**: The ratio along the segment, where 0.0 is `p1` and 1.0 is `p2`
+	/// 
+	/// **Returns**: Returns the point on the spline segment
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let p0 = Vector3::new(0.0, 0.0, 0.0);
+	/// let p1 = Vector3::new(1.0, 0.0, 0.0);
+	/// let p2 = Vector3::new(2.0, 1.0, 0.0);
+	/// let p3 = Vector3::new(3.0, 1.0, 0.0);
+	/// assert_eq!(p1, Vector3::catmull_rom(p0, p1, p2, p3, 0.0));
+	/// assert_eq!(p2, Vector3::catmull_rom(p0, p1, p2, p3, 1.0));
+	/// assert_eq!(Vector3::new(1.5, 0.5, 0.0), Vector3::catmull_rom(p0, p1, p2, p3, 0.5));
+	/// ```
+	pub fn catmull_rom(p0: Vector3, p1: Vector3, p2: Vector3, p3: Vector3, t: f32) -> Vector3 {
+		let t2 = t * t;
+		let t3 = t2 * t;
+		
+		0.5 * (
+			2.0 * p1
+			+ t * (p2 - p0)
+			+ t2 * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3)
+			+ t3 * (3.0 * p1 - p0 - 3.0 * p2 + p3)
+		)
+	}
+	
+	/// Clamps each component of this vector between the components of the min and max vectors
+	/// - **min**: The vector holding the lowest value of each component
+	/// - **max**: The vector holding the highest value of each component
+	/// 
+	/// **Returns**: Returns the clamped vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(-2.0, 0.5, 3.0);
+	/// let expected = Vector3::new(-1.0, 0.5, 1.0);
+	/// assert_eq!(expected, vector.clamp(-Vector3::one(), Vector3::one()));
+	/// let vector = Vector3::new(0.25, -0.5, 1.25);
+	/// assert_eq!(vector, vector.clamp(-Vector3::one(), 2.0 * Vector3::one()));
+	/// ```
+	pub fn clamp(self, min: Vector3, max: Vector3) -> Self {
+		Vector3::new(
+			Math::clamp(self.x, min.x, max.x),
+			Math::clamp(self.y, min.y, max.y),
+			Math::clamp(self.z, min.z, max.z)
+		)
+	}
+	
+	/// Clamps the magnitude of this vector so that it's never longer than the max length
+	/// - **max**: The maximum length the vector can have
+	/// 
+	/// **Returns**: Returns the same vector if it's not longer than the max length, otherwise returns the vector scaled down to the max length
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let vector = Vector3::new(3.0, 0.0, 4.0).clamp_magnitude(2.5);
+	/// assert_eq!(Vector3::new(1.5, 0.0, 2.0), vector);
+	/// assert_range!(2.5, vector.magnitude());
+	/// let vector = Vector3::new(1.0, 2.0, 2.0).clamp_magnitude(5.0);
+	/// assert_eq!(Vector3::new(1.0, 2.0, 2.0), vector);
+	/// assert_eq!(Vector3::zero(), Vector3::zero().clamp_magnitude(1.0));
+	/// ```
+	pub fn clamp_magnitude(self, max: f32) -> Self {
+		let magnitude = self.magnitude();
+		
+		if magnitude <= max || magnitude == 0.0 { self }
+		else { (max / magnitude) * self }
+	}
+	
+	/// Divides each component of the vector by the matching component of the other vector
+	/// - **rhs**: The other vector to divide with
+	/// 
+	/// **Returns**: Returns the component-wise divided vector
+	/// #### Remarks
+	/// Any component divided by zero becomes 0, the same as dividing the whole vector by a scalar of zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(2.0, 9.0, -4.0);
+	/// let b = Vector3::new(4.0, 3.0, 0.0);
+	/// assert_eq!(Vector3::new(0.5, 3.0, 0.0), a.component_div(b));
+	/// let c = Vector3::new(4.0, 3.0, 2.0);
+	/// assert_eq!(a, a.component_mul(c).component_div(c));
+	/// ```
+	pub fn component_div(self, rhs: Vector3) -> Self {
+		Vector3::new(
+			if rhs.x == 0.0 { 0.0 } else { self.x / rhs.x },
+			if rhs.y == 0.0 { 0.0 } else { self.y / rhs.y },
+			if rhs.z == 0.0 { 0.0 } else { self.z / rhs.z }
+		)
+	}
+	
+	/// Multiplies each component of the vector with the matching component of the other vector,
+	/// also known as the Hadamard product. This is the same as `scale`
+	/// - **rhs**: The other vector to multiply with
+	/// 
+	/// **Returns**: Returns the component-wise multiplied vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(2.0, 9.0, -4.0);
+	/// let b = Vector3::new(4.0, 3.0, 0.5);
+	/// assert_eq!(Vector3::new(8.0, 27.0, -2.0), a.component_mul(b));
+	/// assert_eq!(a.scale(b), a.component_mul(b));
+	/// ```
+	pub fn component_mul(self, rhs: Vector3) -> Self { self.scale(rhs) }
+	
 	/// Performs a cross product and creates a 3D vector that is orthogonal to both vectors provided
 	/// - **rhs**: The other vector to cross product
 	/// 
@@ -382,6 +686,55 @@ impl Vector3 {
 		self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
 	}
 	
+	/// Gets the dot product of the two vectors using fused multiply-adds
+	/// - **rhs**: The other vector to dot product with
+	/// 
+	/// **Returns**: Returns the dot product
+	/// #### Remarks
+	/// This rounds fewer times than [`Vector3::dot`] when using the standard library. With `no_std` it falls back
+	/// to separate multiplies and adds, so results may differ slightly between the two
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(1.0, 2.0, 3.0);
+	/// let b = Vector3::new(4.0, -5.0, 6.0);
+	/// assert_eq!(12.0, a.dot_fma(b));
+	/// assert_eq!(a.dot(b), a.dot_fma(b));
+	/// ```
+	pub fn dot_fma(self, rhs: Vector3) -> f32 {
+		Math::fma(self.x, rhs.x, Math::fma(self.y, rhs.y, self.z * rhs.z))
+	}
+	
+	/// Finds if all the components of the vector are finite, being neither NaN nor infinite
+	/// 
+	/// **Returns**: Returns true if all the components are finite
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(1.0, -2.0, 3.0);
+	/// assert!(vector.is_finite());
+	/// let vector = Vector3::new(1.0, f32::NAN, 3.0);
+	/// assert!(!vector.is_finite());
+	/// let vector = Vector3::new(f32::NEG_INFINITY, 0.0, 0.0);
+	/// assert!(!vector.is_finite());
+	/// ```
+	pub fn is_finite(&self) -> bool { self.to_array().iter().all(|value| value.is_finite()) }
+	
+	/// Finds if the vector is a unit vector, having a magnitude of approximately 1
+	/// 
+	/// **Returns**: Returns true if the vector is normalized
+	/// #### Remarks
+	/// This checks the square magnitude, so it avoids computing a square root
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(1.0, 2.0, 3.0);
+	/// assert!(vector.normalize().is_normalized());
+	/// assert!(!(2.0 * vector.normalize()).is_normalized());
+	/// assert!(!Vector3::zero().is_normalized());
+	/// ```
+	pub fn is_normalized(&self) -> bool { Math::approx(self.square_magnitude(), 1.0) }
+	
 	/// Linearly interpolates between the this and the other vector
 	/// - **rhs**: The other vector to end from
 	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
@@ -418,6 +771,44 @@ impl Vector3 {
 		)
 	}
 	
+	/// Gets the largest value of each component between the two vectors
+	/// - **rhs**: The other vector to compare with
+	/// 
+	/// **Returns**: Returns the vector holding the largest value of each component
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(1.0, -2.0, 3.0);
+	/// let b = Vector3::new(-1.0, 2.0, 0.5);
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), a.max(b));
+	/// ```
+	pub fn max(self, rhs: Vector3) -> Self {
+		Vector3::new(
+			Math::max(self.x, rhs.x),
+			Math::max(self.y, rhs.y),
+			Math::max(self.z, rhs.z)
+		)
+	}
+	
+	/// Gets the smallest value of each component between the two vectors
+	/// - **rhs**: The other vector to compare with
+	/// 
+	/// **Returns**: Returns the vector holding the smallest value of each component
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let a = Vector3::new(1.0, -2.0, 3.0);
+	/// let b = Vector3::new(-1.0, 2.0, 0.5);
+	/// assert_eq!(Vector3::new(-1.0, -2.0, 0.5), a.min(b));
+	/// ```
+	pub fn min(self, rhs: Vector3) -> Self {
+		Vector3::new(
+			Math::min(self.x, rhs.x),
+			Math::min(self.y, rhs.y),
+			Math::min(self.z, rhs.z)
+		)
+	}
+	
 	/// Moves this vector towards the target vector, it will never move past the target
 	/// - **target**: The target vector to move towards
 	/// - **delta**: The delta distance to try and move with, defines the maximum distance moved
@@ -461,6 +852,29 @@ impl Vector3 {
 	/// ```
 	pub fn normalize(self) -> Self { self / self.magnitude() }
 	
+	/// Normalizes the vector while also returning the original magnitude, using only a single square root
+	/// 
+	/// **Returns**: Returns a tuple of the unit vector version of this vector and the original magnitude,
+	/// returns a zero vector with a zero magnitude if this vector is a zero vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let vector = Vector3::new(2.0, 3.0, 6.0);
+	/// let (unit, magnitude) = vector.normalize_with_magnitude();
+	/// assert_eq!(vector.normalize(), unit);
+	/// assert_range!(vector.magnitude(), magnitude);
+	/// assert_range!(7.0, magnitude);
+	/// let (unit, magnitude) = Vector3::zero().normalize_with_magnitude();
+	/// assert_eq!(Vector3::zero(), unit);
+	/// assert_eq!(0.0, magnitude);
+	/// ```
+	pub fn normalize_with_magnitude(self) -> (Self, f32) {
+		let magnitude = self.magnitude();
+		
+		if magnitude == 0.0 { (Vector3::zero(), 0.0) }
+		else { (self / magnitude, magnitude) }
+	}
+	
 	/// Projects this vector onto the given vector
 	/// - **rhs**: The vector to project onto
 	/// 
@@ -480,6 +894,29 @@ impl Vector3 {
 		return (top / bottom) * rhs;
 	}
 	
+	/// Projects each of the vectors onto the given vector
+	/// - **vectors**: The vectors to project
+	/// - **rhs**: The vector to project onto
+	/// 
+	/// **Returns**: Returns the projected vectors, in the same order as the given vectors
+	/// #### Remarks
+	/// This allocates the list of vectors and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-1.0, 0.5, 2.0), Vector3::zero()];
+	/// let onto = Vector3::new(4.0, 5.0, 6.0);
+	/// let projected = Vector3::project_all(&vectors, onto);
+	/// assert_eq!(vectors.len(), projected.len());
+	/// for (vector, actual) in vectors.iter().zip(projected) {
+	///     assert_eq!(vector.project(onto), actual);
+	/// }
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn project_all(vectors: &[Vector3], rhs: Vector3) -> Vec<Vector3> {
+		vectors.iter().map(|vector| vector.project(rhs)).collect()
+	}
+	
 	/// Rejects this vector from the given vector
 	/// - **rhs**: The vector to reject from
 	/// 
@@ -496,10 +933,33 @@ impl Vector3 {
 		self - self.project(rhs)
 	}
 	
+	/// Rejects each of the vectors from the given vector
+	/// - **vectors**: The vectors to reject
+	/// - **rhs**: The vector to reject from
+	/// 
+	/// **Returns**: Returns the rejected vectors, in the same order as the given vectors
+	/// #### Remarks
+	/// This allocates the list of vectors and does not appear if using the `no_std` feature
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vectors = [Vector3::new(1.0, 2.0, 3.0), Vector3::new(-1.0, 0.5, 2.0)];
+	/// let rhs = Vector3::new(4.0, 5.0, 6.0);
+	/// let rejected = Vector3::reject_all(&vectors, rhs);
+	/// assert_eq!(vec![vectors[0].reject(rhs), vectors[1].reject(rhs)], rejected);
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn reject_all(vectors: &[Vector3], rhs: Vector3) -> Vec<Vector3> {
+		vectors.iter().map(|vector| vector.reject(rhs)).collect()
+	}
+	
 	/// Reflects this vector using a normal vector
 	/// - **normal**: The normal vector to reflect off of
 	/// 
 	/// **Returns**: Returns the reflected vector
+	/// #### Remarks
+	/// The normal is expected to be normalized, otherwise the reflected vector gets stretched by the
+	/// square of the normal's magnitude. Use `reflect_unnormalized` if the normal might not be a unit vector
 	/// #### Examples
 	/// ```
 	/// # use mathx::Vector3;
@@ -518,6 +978,78 @@ impl Vector3 {
 		return dot * normal + self;
 	}
 	
+	/// Reflects each of the vectors using a normal vector
+	/// - **vectors**: The vectors to reflect
+	/// - **normal**: The normal vector to reflect off of
+	/// 
+	/// **Returns**: Returns the reflected vectors, in the same order as the given vectors
+	/// #### Remarks
+	/// This allocates the list of vectors and does not appear if using the `no_std` feature.
+	/// The normal is expected to be normalized, the same as `reflect`
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vectors = [Vector3::new(1.0, 0.0, 1.0), Vector3::new(0.25, -0.5, 1.25)];
+	/// let normal = Vector3::new(0.0, 0.0, -1.0);
+	/// let reflected = Vector3::reflect_all(&vectors, normal);
+	/// assert_eq!(vec![Vector3::new(1.0, 0.0, -1.0), Vector3::new(0.25, -0.5, -1.25)], reflected);
+	/// for (vector, actual) in vectors.iter().zip(reflected) {
+	///     assert_eq!(vector.reflect(normal), actual);
+	/// }
+	/// assert!(Vector3::reflect_all(&[], normal).is_empty());
+	/// ```
+	#[cfg(not(feature = "no_std"))]
+	pub fn reflect_all(vectors: &[Vector3], normal: Vector3) -> Vec<Vector3> {
+		vectors.iter().map(|vector| vector.reflect(normal)).collect()
+	}
+	
+	/// Reflects this vector using a normal vector that doesn't need to be normalized
+	/// - **normal**: The normal vector to reflect off of, which gets normalized before reflecting
+	/// 
+	/// **Returns**: Returns the reflected vector, which keeps the magnitude of this vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let direction = Vector3::new(0.25, -0.5, 1.25);
+	/// let normal = Vector3::new(1.0, 0.5, -1.0);
+	/// let reflected = direction.reflect_unnormalized(normal);
+	/// assert_eq!(direction.reflect(normal.normalize()), reflected);
+	/// assert_eq!(Vector3::new(1.3611112, 0.055555582, 0.13888884), reflected);
+	/// assert_range!(direction.magnitude(), reflected.magnitude());
+	/// assert_ne!(direction.reflect(normal), reflected);
+	/// ```
+	pub fn reflect_unnormalized(self, normal: Vector3) -> Self { self.reflect(normal.normalize()) }
+	
+	/// Refracts this vector through a surface using Snell's law
+	/// - **normal**: The normal vector of the surface, pointing against this vector
+	/// - **eta**: The ratio of the refractive indices (incoming medium over outgoing medium)
+	/// 
+	/// **Returns**: Returns the refracted unit vector, returns a zero vector on total internal reflection
+	/// #### Remarks
+	/// Both this vector and the normal are normalized before refracting
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let direction = Vector3::new(1.0, -1.0, 0.0).normalize();
+	/// assert_eq!(direction, direction.refract(Vector3::up(), 1.0));
+	/// let direction = Vector3::new(0.5, -0.8660254, 0.0);
+	/// let refracted = direction.refract(Vector3::up(), 1.0 / 1.5);
+	/// assert_range!(0.33333334, refracted.x());
+	/// assert_range!(-0.94280904, refracted.y());
+	/// assert_range!(0.0, refracted.z());
+	/// let direction = Vector3::new(1.0, -0.2, 0.0);
+	/// assert_eq!(Vector3::zero(), direction.refract(Vector3::up(), 1.5));
+	/// ```
+	pub fn refract(self, normal: Vector3, eta: f32) -> Self {
+		let incident = self.normalize();
+		let normal = normal.normalize();
+		let dot = incident * normal;
+		let discriminant = 1.0 - eta * eta * (1.0 - dot * dot);
+		
+		if discriminant < 0.0 { Vector3::zero() }
+		else { eta * incident - (eta * dot + Math::sqrt(discriminant)) * normal }
+	}
+	
 	/// Rotates the vector around towards the target vector
 	/// - **target**: The target vector to rotate towards
 	/// - **radians_delta**: The maximum angle delta the vector will rotate in radians
@@ -634,6 +1166,9 @@ impl Vector3 {
 	/// assert_range!(expected.x(), actual.x(), 0.0001);
 	/// assert_range!(expected.y(), actual.y(), 0.0001);
 	/// assert_range!(expected.z(), actual.z(), 0.0001);
+	/// let actual = Vector3::zero().slerp(b, 0.5);
+	/// assert!(actual.is_finite());
+	/// assert_eq!(Vector3::zero().lerp(b, 0.5), actual);
 	/// ```
 	pub fn slerp(self, rhs: Vector3, t: f32) -> Self { self.slerp_unclamped(rhs, Math::clamp(t, 0.0, 1.0)) }
 	
@@ -642,6 +1177,9 @@ impl Vector3 {
 	/// - **t**: The ratio (t) to interpolate with (not clamped)
 	/// 
 	/// **Returns**: Returns the spherically interpolated vector
+	/// #### Remarks
+	/// If either vector is approximately zero there is no direction to rotate from or towards,
+	/// so this falls back to linearly interpolating the vectors instead
 	/// #### Examples
 	/// ```
 	/// # use mathx::{Vector3,Math,assert_range};
@@ -652,8 +1190,17 @@ impl Vector3 {
 	/// assert_range!(expected.x(), actual.x(), 0.0001);
 	/// assert_range!(expected.y(), actual.y(), 0.0001);
 	/// assert_range!(expected.z(), actual.z(), 0.0001);
+	/// let actual = Vector3::zero().slerp_unclamped(b, 0.25);
+	/// assert_eq!(Vector3::zero().lerp_unclamped(b, 0.25), actual);
+	/// assert_eq!(Vector3::new(1.0, 1.5, 1.75), actual);
+	/// let actual = a.slerp_unclamped(Vector3::zero(), 1.5);
+	/// assert_eq!(Vector3::new(-0.5, -1.5, -2.0), actual);
 	/// ```
 	pub fn slerp_unclamped(self, rhs: Vector3, t: f32) -> Self {
+		if Math::approx_zero(self.magnitude()) || Math::approx_zero(rhs.magnitude()) {
+			return self.lerp_unclamped(rhs, t);
+		}
+		
 		let size = Math::lerp_unclamped(self.magnitude(), rhs.magnitude(), t);
 		let unit_self = self.normalize();
 		let mut unit_rhs = rhs.normalize();
@@ -738,13 +1285,211 @@ impl Vector3 {
 		
 		return (result, velocity);
 	}
+	
+	/// Snaps each component of the vector to the nearest multiple of the matching component of the increment,
+	/// useful for snapping onto a grid
+	/// - **increment**: The size of each step of the grid along each axis, a component that is not greater than 0.0 leaves that component as is
+	/// 
+	/// **Returns**: Returns the snapped vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(1.3, -1.3, 7.9);
+	/// let expected = Vector3::new(1.5, -1.5, 8.0);
+	/// assert_eq!(expected, vector.snap(Vector3::new(0.5, 0.5, 2.0)));
+	/// let expected = Vector3::new(1.0, -1.3, 8.0);
+	/// assert_eq!(expected, vector.snap(Vector3::new(1.0, 0.0, 1.0)));
+	/// ```
+	pub fn snap(self, increment: Vector3) -> Self {
+		Vector3::new(
+			Math::snap(self.x, increment.x),
+			Math::snap(self.y, increment.y),
+			Math::snap(self.z, increment.z),
+		)
+	}
+	
+	/// Estimates the total length of a Catmull-Rom spline passing through all the given points
+	/// - **points**: The points the spline passes through, in order
+	/// - **samples**: The number of straight segments used to approximate the whole spline
+	/// 
+	/// **Returns**: Returns the estimated length of the spline, returns 0.0 if there are less than 2 points
+	/// #### Remarks
+	/// The length is estimated by sampling the spline and summing the distances between the samples.
+	/// More samples give a more accurate length but cost more to compute, the estimate is always
+	/// slightly shorter than the true length since each sample cuts a corner of the curve
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let points = [Vector3::zero(), Vector3::new(5.0, 0.0, 0.0), Vector3::new(10.0, 0.0, 0.0)];
+	/// assert_range!(10.0, Vector3::spline_arc_length(&points, 32));
+	/// assert_eq!(0.0, Vector3::spline_arc_length(&points[..1], 32));
+	/// ```
+	pub fn spline_arc_length(points: &[Vector3], samples: usize) -> f32 {
+		if points.len() < 2 { return 0.0; }
+		
+		let samples = samples.max(1);
+		let segments = (points.len() - 1) as f32;
+		let mut previous = points[0];
+		let mut length = 0.0;
+		
+		for i in 1..=samples {
+			let point = Vector3::spline_point(points, segments * i as f32 / samples as f32);
+			
+			length += previous.distance(point);
+			previous = point;
+		}
+		
+		length
+	}
+	
+	/// Gets the point on a Catmull-Rom spline that is the given distance along the spline
+	/// - **points**: The points the spline passes through, in order
+	/// - **distance**: The distance along the spline to get the point from
+	/// - **samples**: The number of straight segments used to approximate the whole spline
+	/// 
+	/// **Returns**: Returns the point found at the given distance along the spline, clamped to the ends of the spline.
+	/// Returns a zero vector if there are no points
+	/// #### Remarks
+	/// The spline's `t` parameter does not move at a constant speed, so this walks the sampled
+	/// segments (see `spline_arc_length`) to map the distance back onto the spline. More samples give
+	/// a more accurate point but cost more to compute
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let points = [Vector3::zero(), Vector3::new(2.0, 0.0, 0.0), Vector3::new(10.0, 0.0, 0.0)];
+	/// assert_eq!(Vector3::new(5.0, 0.0, 0.0), Vector3::spline_at_distance(&points, 5.0, 64));
+	/// assert_eq!(Vector3::zero(), Vector3::spline_at_distance(&points, -1.0, 64));
+	/// assert_eq!(Vector3::new(10.0, 0.0, 0.0), Vector3::spline_at_distance(&points, 20.0, 64));
+	/// ```
+	pub fn spline_at_distance(points: &[Vector3], distance: f32, samples: usize) -> Vector3 {
+		if points.is_empty() { return Vector3::zero(); }
+		if points.len() == 1 || distance <= 0.0 { return points[0]; }
+		
+		let samples = samples.max(1);
+		let segments = (points.len() - 1) as f32;
+		let mut previous = points[0];
+		let mut length = 0.0;
+		
+		for i in 1..=samples {
+			let point = Vector3::spline_point(points, segments * i as f32 / samples as f32);
+			let step = previous.distance(point);
+			
+			if step > 0.0 && length + step >= distance {
+				return previous.lerp(point, (distance - length) / step);
+			}
+			
+			length += step;
+			previous = point;
+		}
+		
+		points[points.len() - 1]
+	}
+	
+	/// Normalizes the vector, failing if the vector is too close to zero to have a direction
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector, or `None` if the vector is approximately zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let vector = Vector3::new(0.0, 3.0, 4.0).try_normalize();
+	/// assert_eq!(Some(Vector3::new(0.0, 0.6, 0.8)), vector);
+	/// assert_range!(1.0, vector.unwrap().magnitude());
+	/// assert_eq!(None, Vector3::zero().try_normalize());
+	/// ```
+	pub fn try_normalize(self) -> Option<Self> {
+		let magnitude = self.magnitude();
+		
+		if Math::approx_zero(magnitude) { None }
+		else { Some(self / magnitude) }
+	}
+	
+	/// Rescales this vector so that it's exactly the given length, keeping it's direction
+	/// - **length**: The length the vector should have
+	/// 
+	/// **Returns**: Returns the vector scaled to the given length, returns a zero vector if this vector is zero
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Math,assert_range};
+	/// let vector = Vector3::new(0.0, 3.0, 4.0);
+	/// let scaled = vector.with_magnitude(10.0);
+	/// assert_eq!(Vector3::new(0.0, 6.0, 8.0), scaled);
+	/// assert_range!(10.0, scaled.magnitude());
+	/// assert_eq!(vector.normalize(), scaled.normalize());
+	/// assert_eq!(Vector3::zero(), Vector3::zero().with_magnitude(10.0));
+	/// ```
+	pub fn with_magnitude(self, length: f32) -> Self { length * self.normalize() }
+}
+
+/// Private Methods
+impl Vector3 {
+	/// Gets the point on a Catmull-Rom spline going through all the points
+	/// - **points**: The points the spline passes through, must have at least 2 points
+	/// - **u**: The position along the spline, where every whole number lands on one of the points
+	/// 
+	/// **Returns**: Returns the point on the spline
+	fn spline_point(points: &[Vector3], u: f32) -> Vector3 {
+		let last = points.len() - 1;
+		let index = (Math::floor(u) as usize).min(last - 1);
+		let t = u - index as f32;
+		let p0 = points[index.saturating_sub(1)];
+		let p1 = points[index];
+		let p2 = points[index + 1];
+		let p3 = points[(index + 2).min(last)];
+		
+		Vector3::catmull_rom(p0, p1, p2, p3, t)
+	}
 }
 
 /// Conversions
 impl Vector3 {
+	/// Converts the vector into an array of its components
+	/// 
+	/// **Returns**: Returns the array holding the components in x, y, z order
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector3;
+	/// let vector = Vector3::new(1.0, 2.0, 3.0);
+	/// assert_eq!([1.0, 2.0, 3.0], vector.to_array());
+	/// let array: [f32; 3] = vector.into();
+	/// assert_eq!(vector, Vector3::from_array(array));
+	/// ```
+	pub fn to_array(self) -> [f32; 3] { [self.x, self.y, self.z] }
+	
+	/// Converts the vector into a homogeneous direction, which ignores the translation of a transform
+	/// 
+	/// **Returns**: Returns the 4D vector with the w-coordinate set to 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Vector4};
+	/// let direction = Vector3::new(1.0, 2.0, 3.0).to_direction4();
+	/// assert_eq!(Vector4::new(1.0, 2.0, 3.0, 0.0), direction);
+	/// ```
+	pub fn to_direction4(self) -> Vector4 { Vector4::new(self.x, self.y, self.z, 0.0) }
+	
+	/// Converts the vector into a homogeneous point, which gets moved by the translation of a transform
+	/// 
+	/// **Returns**: Returns the 4D vector with the w-coordinate set to 1.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Vector4};
+	/// let a = Vector3::new(1.0, 2.0, 3.0).to_point4();
+	/// assert_eq!(Vector4::new(1.0, 2.0, 3.0, 1.0), a);
+	/// let b = Vector3::new(4.0, 6.0, 8.0).to_point4();
+	/// assert_eq!(Vector3::new(3.0, 4.0, 5.0).to_direction4(), b - a);
+	/// ```
+	pub fn to_point4(self) -> Vector4 { Vector4::new(self.x, self.y, self.z, 1.0) }
+	
 	pub fn to_vector2(self) -> Vector2 { Vector2::new(self.x, self.y) }
 }
 
+impl From<[f32; 3]> for Vector3 {
+	fn from(value: [f32; 3]) -> Self { Vector3::from_array(value) }
+}
+
+impl From<Vector3> for [f32; 3] {
+	fn from(value: Vector3) -> Self { value.to_array() }
+}
+
 impl From<Vector2> for Vector3 {
 	fn from(value: Vector2) -> Self { Vector3::from_vector2(value) }
 }
@@ -752,16 +1497,62 @@ impl From<Vector2> for Vector3 {
 unsafe impl Send for Vector3 {}
 unsafe impl Sync for Vector3 {}
 
+// Default
+/// Gets the zero vector, the same as `Vector3::zero()`
+/// #### Examples
+/// ```
+/// # use mathx::Vector3;
+/// assert_eq!(Vector3::zero(), Vector3::default());
+/// ```
+impl Default for Vector3 {
+	fn default() -> Self { Vector3::zero() }
+}
+
 // Equates
 impl Eq for Vector3 {}
 impl PartialEq for Vector3 {
-	fn eq(&self, other: &Self) -> bool {
-		Math::approx(self.x, other.x)
-		&& Math::approx(self.y, other.y)
-		&& Math::approx(self.z, other.z)
+	fn eq(&self, other: &Self) -> bool { self.approx_eq(other) }
+}
+
+/// Approximately compares each component of the vector
+/// #### Examples
+/// ```
+/// # use mathx::Vector3;
+/// # use mathx::interfaces::ApproxEq;
+/// let a = Vector3::new(1.0, 2.0, 3.0);
+/// let b = Vector3::new(1.0, 2.0, 3.0001);
+/// assert!(a.approx_eq_eps(&b, 0.001));
+/// assert!(!a.approx_eq(&b));
+/// ```
+impl ApproxEq for Vector3 {
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool {
+		Math::approx_epsilon(self.x, other.x, epsilon)
+		&& Math::approx_epsilon(self.y, other.y, epsilon)
+		&& Math::approx_epsilon(self.z, other.z, epsilon)
 	}
 }
 
+// Hashing
+/// Hashes the exact bits of each component, allowing the vector to be used as a key
+/// #### Examples
+/// ```
+/// # use std::collections::HashMap;
+/// # use mathx::{Exact,Vector3};
+/// let mut map = HashMap::new();
+/// map.insert(Exact(Vector3::new(1.0, 2.0, 3.0)), 10);
+/// assert_eq!(Some(&10), map.get(&Exact(Vector3::new(1.0, 2.0, 3.0))));
+/// assert_eq!(Vector3::new(-0.0, 2.0, 3.0), Vector3::new(0.0, 2.0, 3.0));
+/// assert_ne!(Exact(Vector3::new(-0.0, 2.0, 3.0)), Exact(Vector3::new(0.0, 2.0, 3.0)));
+/// ```
+impl Hash for Exact<Vector3> {
+	fn hash<H: Hasher>(&self, state: &mut H) { self.0.to_array().map(f32::to_bits).hash(state); }
+}
+
+impl Eq for Exact<Vector3> {}
+impl PartialEq for Exact<Vector3> {
+	fn eq(&self, other: &Self) -> bool { self.0.to_array().map(f32::to_bits) == other.0.to_array().map(f32::to_bits) }
+}
+
 // Display
 #[cfg(not(feature = "no_std"))]
 impl std::fmt::Display for Vector3 {
@@ -770,6 +1561,84 @@ impl std::fmt::Display for Vector3 {
 	}
 }
 
+// Parsing
+/// Parses the vector from comma-separated numbers that can be surrounded by parentheses, such as `(1, 2, 3)`
+/// #### Remarks
+/// This accepts the same format as the `Display` output, so `vector.to_string().parse()` gives back the same vector
+/// #### Examples
+/// ```
+/// # use mathx::{Vector3,ParseError};
+/// let vector: Vector3 = "1.0, 2.0, 3.0".parse().unwrap();
+/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), vector);
+/// let vector: Vector3 = " ( 1.5, -2, 3.25 ) ".parse().unwrap();
+/// assert_eq!(Vector3::new(1.5, -2.0, 3.25), vector);
+/// # #[cfg(not(feature = "no_std"))] {
+/// let vector = Vector3::new(0.1, -2.5, 1e-3);
+/// assert_eq!(Ok(vector), vector.to_string().parse());
+/// # }
+/// assert_eq!(Err(ParseError::WrongCount { expected: 3, found: 2 }), "1, 2".parse::<Vector3>());
+/// assert_eq!(Err(ParseError::InvalidNumber { index: 2 }), "1, 2, three".parse::<Vector3>());
+/// assert_eq!(Err(ParseError::UnbalancedParentheses), "1, 2, 3)".parse::<Vector3>());
+/// ```
+impl core::str::FromStr for Vector3 {
+	type Err = ParseError;
+	
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let values = parse_floats(text, ["", "", ""])?;
+		
+		Ok(Vector3::new(values[0], values[1], values[2]))
+	}
+}
+
+// Indexing
+/// Gets the component of the vector by index, where 0 is x, 1 is y, and 2 is z
+/// #### Remarks
+/// This panics if the index is out of range
+/// #### Examples
+/// ```
+/// # use mathx::Vector3;
+/// let vector = Vector3::new(1.0, 2.0, 3.0);
+/// assert_eq!(1.0, vector[0]);
+/// assert_eq!(6.0, (0..3).map(|i| vector[i]).sum::<f32>());
+/// ```
+/// ```should_panic
+/// # use mathx::Vector3;
+/// let vector = Vector3::new(1.0, 2.0, 3.0);
+/// let _ = vector[3];
+/// ```
+impl Index<usize> for Vector3 {
+	type Output = f32;
+	fn index(&self, index: usize) -> &Self::Output {
+		match index {
+			0 => &self.x,
+			1 => &self.y,
+			2 => &self.z,
+			_ => panic!("Vector3 index out of range: {} (expected 0 to 2)", index),
+		}
+	}
+}
+
+/// Gets the mutable component of the vector by index, where 0 is x, 1 is y, and 2 is z
+/// #### Remarks
+/// This panics if the index is out of range
+/// #### Examples
+/// ```
+/// # use mathx::Vector3;
+/// let mut vector = Vector3::new(1.0, 2.0, 3.0);
+/// vector[2] = 5.0;
+/// assert_eq!(Vector3::new(1.0, 2.0, 5.0), vector);
+/// ```
+impl IndexMut<usize> for Vector3 {
+	fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+		match index {
+			0 => &mut self.x,
+			1 => &mut self.y,
+			2 => &mut self.z,
+			_ => panic!("Vector3 index out of range: {} (expected 0 to 2)", index),
+		}
+	}
+}
+
 // Arithmetic
 impl AddSubArithmetic<Vector3> for Vector3 {
 	type Output = Vector3;
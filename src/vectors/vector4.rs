@@ -0,0 +1,396 @@
+
+use core::ops::Neg;
+
+use crate::{Math, interfaces::ApproxEq};
+use crate::Vector3;
+use crate::{AddSubArithmetic, MulDivScalar, use_impl_ops, impl_add, impl_sub, impl_mul, impl_div};
+
+/// A 4D vector that holds an x-coordinate, y-coordinate, z-coordinate, and w-coordinate
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone, Copy)]
+pub struct Vector4 {
+	/// The x coordinate of the vector
+	x: f32,
+	/// The y coordinate of the vector
+	y: f32,
+	/// The z coordinate of the vector
+	z: f32,
+	/// The w coordinate of the vector
+	w: f32,
+}
+
+/// Constructors
+impl Vector4 {
+	/// Creates a new 4D vector
+	/// - **x**: The x coordinate of the vector
+	/// - **y**: The y coordinate of the vector
+	/// - **z**: The z coordinate of the vector
+	/// - **w**: The w coordinate of the vector
+	/// 
+	/// **Returns**: Returns a new 4D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let vector = Vector4::new(1.2, 3.45, 6.789, 10.0);
+	/// assert_eq!(1.2, vector.x());
+	/// assert_eq!(3.45, vector.y());
+	/// assert_eq!(6.789, vector.z());
+	/// assert_eq!(10.0, vector.w());
+	/// ```
+	pub fn new(x: f32, y: f32, z: f32, w: f32) -> Self { Vector4 { x, y, z, w } }
+	
+	/// Creates a new 4D vector from a 3D vector
+	/// - **vector**: The 3D vector to convert from
+	/// 
+	/// **Returns**: Returns a converted 4D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Vector4};
+	/// let vector3 = Vector3::new(1.2, 3.45, 6.789);
+	/// let vector4 = Vector4::from_vector3(vector3);
+	/// assert_eq!(1.2, vector4.x());
+	/// assert_eq!(3.45, vector4.y());
+	/// assert_eq!(6.789, vector4.z());
+	/// assert_eq!(0.0, vector4.w());
+	/// ```
+	pub fn from_vector3(vector: Vector3) -> Self { Vector4::new(vector.x(), vector.y(), vector.z(), 0.0) }
+	
+	/// Creates an empty 4D vector
+	/// 
+	/// **Returns**: Returns an empty 4D vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let vector = Vector4::zero();
+	/// assert_eq!(0.0, vector.x());
+	/// assert_eq!(0.0, vector.y());
+	/// assert_eq!(0.0, vector.z());
+	/// assert_eq!(0.0, vector.w());
+	/// ```
+	pub fn zero() -> Self { Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 0.0 } }
+	
+	/// Creates a 4D vector that contains 1 in all it's components: (1, 1, 1, 1)
+	/// 
+	/// **Returns**: Returns a 4D vector that contains 1 in all it's components
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let vector = Vector4::one();
+	/// assert_eq!(1.0, vector.x());
+	/// assert_eq!(1.0, vector.y());
+	/// assert_eq!(1.0, vector.z());
+	/// assert_eq!(1.0, vector.w());
+	/// ```
+	pub fn one() -> Self { Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 } }
+}
+
+/// Properties
+impl Vector4 {
+	/// Gets the x coordinate of the vector
+	/// 
+	/// **Returns**: Returns the x coordinate of the vector
+	pub fn x(&self) -> f32 { self.x }
+	
+	/// Sets the x coordinate of the vector
+	/// - **value**: The value to set the x coordinate of the vector
+	pub fn set_x(&mut self, value: f32) { self.x = value; }
+	
+	/// Gets the y coordinate of the vector
+	/// 
+	/// **Returns**: Returns the y coordinate of the vector
+	pub fn y(&self) -> f32 { self.y }
+	
+	/// Sets the y coordinate of the vector
+	/// - **value**: The value to set the y coordinate of the vector
+	pub fn set_y(&mut self, value: f32) { self.y = value; }
+	
+	/// Gets the z coordinate of the vector
+	/// 
+	/// **Returns**: Returns the z coordinate of the vector
+	pub fn z(&self) -> f32 { self.z }
+	
+	/// Sets the z coordinate of the vector
+	/// - **value**: The value to set the z coordinate of the vector
+	pub fn set_z(&mut self, value: f32) { self.z = value; }
+	
+	/// Gets the w coordinate of the vector
+	/// 
+	/// **Returns**: Returns the w coordinate of the vector
+	pub fn w(&self) -> f32 { self.w }
+	
+	/// Sets the w coordinate of the vector
+	/// - **value**: The value to set the w coordinate of the vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let mut a = Vector4::zero();
+	/// a.set_w(6.0);
+	/// assert_eq!(6.0, a.w());
+	/// ```
+	pub fn set_w(&mut self, value: f32) { self.w = value; }
+	
+	/// Gets the magnitude of the vector. This returns the length of the vector
+	/// 
+	/// **Returns**: Returns the magnitude of the vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let a = Vector4::new(-1.0, 2.0, -2.0, 4.0);
+	/// assert_eq!(5.0, a.magnitude());
+	/// ```
+	pub fn magnitude(&self) -> f32 {
+		let magnitude = self.square_magnitude();
+		
+		if magnitude == 0.0 || magnitude == 1.0 { magnitude }
+		else { Math::sqrt(magnitude) }
+	}
+	
+	/// Gets the magnitude squared, avoiding the use of a square root
+	/// 
+	/// **Returns**: Returns the magnitude of the vector squared
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let a = Vector4::new(-1.0, 2.0, 2.0, 4.0);
+	/// assert_eq!(25.0, a.square_magnitude());
+	/// ```
+	pub fn square_magnitude(&self) -> f32 { self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w }
+}
+
+/// Public Methods
+impl Vector4 {
+	/// Gets the distance between the two vectors
+	/// - **rhs**: The other vector to get the distance between
+	/// 
+	/// **Returns**: Returns the distance between the two vectors
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+	/// let b = Vector4::new(2.0, 4.0, 5.0, 8.0);
+	/// assert_eq!(5.0, a.distance(b));
+	/// ```
+	pub fn distance(self, rhs: Vector4) -> f32 { (rhs - self).magnitude() }
+	
+	/// Gets the dot product of between the two vectors
+	/// - **rhs**: The other vector to dot product with
+	/// 
+	/// **Returns**: Returns the dot product
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let a = Vector4::one();
+	/// let b = Vector4::new(0.25, 1.1, -4.1, 2.0);
+	/// assert_eq!(-0.75, a.dot(b));
+	/// assert_eq!(-0.75, a * b);
+	/// ```
+	pub fn dot(self, rhs: Vector4) -> f32 {
+		self.x * rhs.x + self.y * rhs.y + self.z * rhs.z + self.w * rhs.w
+	}
+	
+	/// Linearly interpolates between the this and the other vector
+	/// - **rhs**: The other vector to end from
+	/// - **t**: The ratio value to interpolate between both vectors. Clamped between 0.0 and 1.0
+	/// 
+	/// **Returns**: Returns the interpolated vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let a = Vector4::new(0.0, 4.0, -10.0, 1.0);
+	/// let b = Vector4::new(1.0, 10.0, -4.0, 2.0);
+	/// let expected = Vector4::new(0.7, 8.2, -5.8, 1.7);
+	/// assert_eq!(expected, a.lerp(b, 0.7));
+	/// assert_eq!(b, a.lerp(b, 1.5));
+	/// ```
+	pub fn lerp(self, rhs: Vector4, t: f32) -> Self { self.lerp_unclamped(rhs, Math::clamp(t, 0.0, 1.0)) }
+	
+	/// Linearly interpolates between the this and the other vector (not clamped)
+	/// - **rhs**: The other vector to end from
+	/// - **t**: The ratio value to interpolate between both vectors
+	/// 
+	/// **Returns**: Returns the interpolated vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::Vector4;
+	/// let a = Vector4::new(0.0, 4.0, -10.0, 1.0);
+	/// let b = Vector4::new(1.0, 10.0, -4.0, 2.0);
+	/// let expected = Vector4::new(1.5, 13.0, -1.0, 2.5);
+	/// assert_eq!(expected, a.lerp_unclamped(b, 1.5));
+	/// ```
+	pub fn lerp_unclamped(self, rhs: Vector4, t: f32) -> Self {
+		Vector4::new(
+			Math::lerp_unclamped(self.x, rhs.x, t),
+			Math::lerp_unclamped(self.y, rhs.y, t),
+			Math::lerp_unclamped(self.z, rhs.z, t),
+			Math::lerp_unclamped(self.w, rhs.w, t)
+		)
+	}
+	
+	/// Normalizes the vector
+	/// 
+	/// **Returns**: Returns the unit vector version of this vector
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector4,Math,assert_range};
+	/// let vector = Vector4::one().normalize();
+	/// assert_range!(0.5, vector.x());
+	/// assert_range!(0.5, vector.y());
+	/// assert_range!(0.5, vector.z());
+	/// assert_range!(0.5, vector.w());
+	/// let vector = Vector4::new(-1.0, 2.0, -2.0, 4.0).normalize();
+	/// assert_range!(-0.2, vector.x());
+	/// assert_range!(0.4, vector.y());
+	/// assert_range!(-0.4, vector.z());
+	/// assert_range!(0.8, vector.w());
+	/// ```
+	pub fn normalize(self) -> Self { self / self.magnitude() }
+}
+
+/// Conversions
+impl Vector4 {
+	pub fn to_vector3(self) -> Vector3 { Vector3::new(self.x, self.y, self.z) }
+	
+	/// Converts the homogeneous vector into a 3D point by dividing by the w-coordinate (the perspective divide)
+	/// 
+	/// **Returns**: Returns the 3D point, returns the zero vector if the w-coordinate is 0.0
+	/// #### Examples
+	/// ```
+	/// # use mathx::{Vector3,Vector4};
+	/// let vector = Vector4::new(2.0, 4.0, 6.0, 2.0);
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), vector.to_vector3_homogeneous());
+	/// let point = Vector3::new(1.0, 2.0, 3.0).to_point4();
+	/// assert_eq!(Vector3::new(1.0, 2.0, 3.0), point.to_vector3_homogeneous());
+	/// let direction = Vector3::new(1.0, 2.0, 3.0).to_direction4();
+	/// assert_eq!(Vector3::zero(), direction.to_vector3_homogeneous());
+	/// ```
+	pub fn to_vector3_homogeneous(self) -> Vector3 { self.to_vector3() / self.w }
+}
+
+impl From<Vector3> for Vector4 {
+	fn from(value: Vector3) -> Self { Vector4::from_vector3(value) }
+}
+
+impl From<Vector4> for Vector3 {
+	fn from(value: Vector4) -> Self { value.to_vector3() }
+}
+
+unsafe impl Send for Vector4 {}
+unsafe impl Sync for Vector4 {}
+
+// Default
+/// Gets the zero vector, the same as `Vector4::zero()`
+/// #### Examples
+/// ```
+/// # use mathx::Vector4;
+/// assert_eq!(Vector4::zero(), Vector4::default());
+/// ```
+impl Default for Vector4 {
+	fn default() -> Self { Vector4::zero() }
+}
+
+// Equates
+impl Eq for Vector4 {}
+impl PartialEq for Vector4 {
+	fn eq(&self, other: &Self) -> bool { self.approx_eq(other) }
+}
+
+/// Approximately compares each component of the vector
+/// #### Examples
+/// ```
+/// # use mathx::Vector4;
+/// # use mathx::interfaces::ApproxEq;
+/// let a = Vector4::new(1.0, 2.0, 3.0, 4.0);
+/// let b = Vector4::new(1.0, 2.0, 3.0, 4.0001);
+/// assert!(a.approx_eq_eps(&b, 0.001));
+/// assert!(!a.approx_eq(&b));
+/// ```
+impl ApproxEq for Vector4 {
+	fn approx_eq_eps(&self, other: &Self, epsilon: f32) -> bool {
+		Math::approx_epsilon(self.x, other.x, epsilon)
+		&& Math::approx_epsilon(self.y, other.y, epsilon)
+		&& Math::approx_epsilon(self.z, other.z, epsilon)
+		&& Math::approx_epsilon(self.w, other.w, epsilon)
+	}
+}
+
+// Display
+#[cfg(not(feature = "no_std"))]
+impl std::fmt::Display for Vector4 {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str(&format!("({}, {}, {}, {})", self.x, self.y, self.z, self.w))
+	}
+}
+
+// Arithmetic
+impl AddSubArithmetic<Vector4> for Vector4 {
+	type Output = Vector4;
+	fn add_other(self, rhs: Vector4) -> Self::Output {
+		Vector4 { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w }
+	}
+	fn add_assign_other(&mut self, rhs: Vector4) {
+		self.x += rhs.x;
+		self.y += rhs.y;
+		self.z += rhs.z;
+		self.w += rhs.w;
+	}
+	fn subtract_other(self, rhs: Vector4) -> Self::Output {
+		Vector4 { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w }
+	}
+	fn subtract_assign_other(&mut self, rhs: Vector4) {
+		self.x -= rhs.x;
+		self.y -= rhs.y;
+		self.z -= rhs.z;
+		self.w -= rhs.w;
+	}
+}
+
+impl MulDivScalar for Vector4 {
+	type Output = Vector4;
+	fn multiply_scalar(self, rhs: f32) -> Self::Output {
+		Vector4 { x: rhs * self.x, y: rhs * self.y, z: rhs * self.z, w: rhs * self.w }
+	}
+	fn multiply_assign_scalar(&mut self, rhs: f32) {
+		self.x *= rhs;
+		self.y *= rhs;
+		self.z *= rhs;
+		self.w *= rhs;
+	}
+	fn divide_scalar(self, rhs: f32) -> Self::Output {
+		if rhs == 0.0 { return Vector4::zero(); }
+		Vector4 { x: self.x / rhs, y: self.y / rhs, z: self.z / rhs, w: self.w / rhs }
+	}
+	fn divide_assign_scalar(&mut self, rhs: f32) {
+		if rhs == 0.0 {
+			self.x = 0.0;
+			self.y = 0.0;
+			self.z = 0.0;
+			self.w = 0.0;
+		}
+		else {
+			self.x /= rhs;
+			self.y /= rhs;
+			self.z /= rhs;
+			self.w /= rhs;
+		}
+	}
+	fn reciprocal_scalar(self, rhs: f32) -> Self::Output {
+		Vector4 {
+			x: if self.x != 0.0 { rhs / self.x } else { 0.0 },
+			y: if self.y != 0.0 { rhs / self.y } else { 0.0 },
+			z: if self.z != 0.0 { rhs / self.z } else { 0.0 },
+			w: if self.w != 0.0 { rhs / self.w } else { 0.0 },
+		}
+	}
+}
+
+impl Neg for Vector4 {
+	type Output = Vector4;
+	fn neg(self) -> Self::Output { Vector4::new(-self.x, -self.y, -self.z, -self.w) }
+}
+
+use_impl_ops!();
+impl_add!(Vector4);
+impl_sub!(Vector4);
+impl_mul!(Vector4, Vector4 => f32: dot);
+impl_mul!(Vector4);
+impl_div!(Vector4);
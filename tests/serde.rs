@@ -0,0 +1,71 @@
+#![cfg(feature = "serde")]
+
+#[cfg(not(feature = "no_colors"))]
+use mathx::Color;
+#[cfg(not(any(feature = "no_planes", feature = "no_vectors")))]
+use mathx::Plane;
+#[cfg(not(any(feature = "no_rays", feature = "no_vectors")))]
+use mathx::{Ray2, Ray3, Vector2};
+#[cfg(not(any(feature = "no_vectors", all(feature = "no_planes", feature = "no_rays"))))]
+use mathx::Vector3;
+
+#[cfg(not(any(feature = "no_planes", feature = "no_vectors")))]
+#[test]
+fn plane_round_trips_through_json() {
+	let plane = Plane::new(Vector3::new(1.0, -2.0, 3.0), 3.0);
+	let json = serde_json::to_string(&plane).unwrap();
+	let actual: Plane = serde_json::from_str(&json).unwrap();
+	
+	assert_eq!(plane, actual);
+}
+
+#[cfg(not(any(feature = "no_rays", feature = "no_vectors")))]
+#[test]
+fn ray2_round_trips_through_json() {
+	let ray = Ray2::new(Vector2::new(1.0, 2.0), Vector2::new(-0.5, 4.0));
+	let json = serde_json::to_string(&ray).unwrap();
+	let actual: Ray2 = serde_json::from_str(&json).unwrap();
+	
+	assert_eq!(ray, actual);
+}
+
+#[cfg(not(any(feature = "no_rays", feature = "no_vectors")))]
+#[test]
+fn ray3_round_trips_through_json() {
+	let ray = Ray3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::new(-0.5, 4.0, 0.25));
+	let json = serde_json::to_string(&ray).unwrap();
+	let actual: Ray3 = serde_json::from_str(&json).unwrap();
+	
+	assert_eq!(ray, actual);
+}
+
+#[cfg(all(feature = "serde_hex", not(feature = "no_colors")))]
+#[test]
+fn color_round_trips_through_hex_string() {
+	let tomato = Color::new_str("tomato");
+	let json = serde_json::to_string(&tomato).unwrap();
+	let actual: Color = serde_json::from_str(&json).unwrap();
+	
+	assert_eq!("\"#FF6347FF\"", json);
+	assert_eq!(tomato, actual);
+}
+
+#[cfg(all(feature = "serde_hex", not(feature = "no_colors")))]
+#[test]
+fn color_deserializes_from_hex_code_or_name() {
+	let tomato = Color::new_str("tomato");
+	
+	assert_eq!(tomato, serde_json::from_str::<Color>("\"#FF6347\"").unwrap());
+	assert_eq!(tomato, serde_json::from_str::<Color>("\"tomato\"").unwrap());
+	assert!(serde_json::from_str::<Color>("\"#GG0000\"").is_err());
+}
+
+#[cfg(not(any(feature = "serde_hex", feature = "no_colors")))]
+#[test]
+fn color_round_trips_through_json() {
+	let color = Color::new_alpha(0.25, 0.5, 0.75, 1.0);
+	let json = serde_json::to_string(&color).unwrap();
+	let actual: Color = serde_json::from_str(&json).unwrap();
+	
+	assert_eq!(color, actual);
+}